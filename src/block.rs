@@ -0,0 +1,530 @@
+use std::ops::Range;
+
+use crate::{LinalgError, Matrix};
+
+impl Matrix {
+    /// Places another matrix to the right of this one.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: Matrix with the same number of rows as `self`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the `rows` x `(cols + other.cols)` matrix or an error if the
+    /// row counts differ.
+    pub fn hstack(&self, other: &Matrix) -> Result<Matrix, LinalgError> {
+        if other.rows != self.rows {
+            return Err(LinalgError::ShapeMismatch {
+                expected: (self.rows, other.cols),
+                found: other.shape(),
+            });
+        }
+        let mut data = Vec::with_capacity(self.data.len() + other.data.len());
+        for i in 0..self.rows {
+            data.extend_from_slice(&self.data[i * self.cols..(i + 1) * self.cols]);
+            data.extend_from_slice(&other.data[i * other.cols..(i + 1) * other.cols]);
+        }
+        Ok(Matrix {
+            rows: self.rows,
+            cols: self.cols + other.cols,
+            data,
+        })
+    }
+
+    /// Places another matrix below this one.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: Matrix with the same number of columns as `self`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the `(rows + other.rows)` x `cols` matrix or an error if the
+    /// column counts differ.
+    pub fn vstack(&self, other: &Matrix) -> Result<Matrix, LinalgError> {
+        if other.cols != self.cols {
+            return Err(LinalgError::ShapeMismatch {
+                expected: (other.rows, self.cols),
+                found: other.shape(),
+            });
+        }
+        let mut data = Vec::with_capacity(self.data.len() + other.data.len());
+        data.extend_from_slice(&self.data);
+        data.extend_from_slice(&other.data);
+        Ok(Matrix {
+            rows: self.rows + other.rows,
+            cols: self.cols,
+            data,
+        })
+    }
+
+    /// Places several matrices side by side, left to right.
+    ///
+    /// # Parameters
+    ///
+    /// - `mats`: Non-empty list of matrices that all have the same number of rows.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the stacked matrix or an error if the list is empty or a
+    /// matrix's row count differs from the first one's.
+    pub fn hstack_all(mats: &[&Matrix]) -> Result<Matrix, LinalgError> {
+        let first = mats.first().ok_or_else(|| {
+            LinalgError::InvalidArgument("Cannot stack an empty list of matrices".to_string())
+        })?;
+        if let Some(m) = mats.iter().find(|m| m.rows != first.rows) {
+            return Err(LinalgError::ShapeMismatch {
+                expected: (first.rows, m.cols),
+                found: m.shape(),
+            });
+        }
+        let cols = mats.iter().map(|m| m.cols).sum();
+        let mut data = Vec::with_capacity(first.rows * cols);
+        for i in 0..first.rows {
+            for m in mats {
+                data.extend_from_slice(&m.data[i * m.cols..(i + 1) * m.cols]);
+            }
+        }
+        Ok(Matrix {
+            rows: first.rows,
+            cols,
+            data,
+        })
+    }
+
+    /// Places several matrices on top of each other, top to bottom.
+    ///
+    /// # Parameters
+    ///
+    /// - `mats`: Non-empty list of matrices that all have the same number of columns.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the stacked matrix or an error if the list is empty or a
+    /// matrix's column count differs from the first one's.
+    pub fn vstack_all(mats: &[&Matrix]) -> Result<Matrix, LinalgError> {
+        let first = mats.first().ok_or_else(|| {
+            LinalgError::InvalidArgument("Cannot stack an empty list of matrices".to_string())
+        })?;
+        if let Some(m) = mats.iter().find(|m| m.cols != first.cols) {
+            return Err(LinalgError::ShapeMismatch {
+                expected: (m.rows, first.cols),
+                found: m.shape(),
+            });
+        }
+        let rows = mats.iter().map(|m| m.rows).sum();
+        let mut data = Vec::with_capacity(rows * first.cols);
+        for m in mats {
+            data.extend_from_slice(&m.data);
+        }
+        Ok(Matrix {
+            rows,
+            cols: first.cols,
+            data,
+        })
+    }
+
+    /// Splits the matrix into the rows above `at` and the rows from `at` on.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of an `at` x `cols` matrix and a `(rows - at)` x `cols` matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > rows`.
+    pub fn split_at_row(mut self, at: usize) -> (Matrix, Matrix) {
+        if at > self.rows {
+            panic!(
+                "Cannot split a matrix with {} rows at row {}.",
+                self.rows, at
+            );
+        }
+        let bottom = self.data.split_off(at * self.cols);
+        (
+            Matrix {
+                rows: at,
+                cols: self.cols,
+                data: self.data,
+            },
+            Matrix {
+                rows: self.rows - at,
+                cols: self.cols,
+                data: bottom,
+            },
+        )
+    }
+
+    /// Splits the matrix into the columns left of `at` and the columns from `at` on.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of a `rows` x `at` matrix and a `rows` x `(cols - at)` matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > cols`.
+    pub fn split_at_col(self, at: usize) -> (Matrix, Matrix) {
+        if at > self.cols {
+            panic!(
+                "Cannot split a matrix with {} columns at column {}.",
+                self.cols, at
+            );
+        }
+        let mut left = Vec::with_capacity(self.rows * at);
+        let mut right = Vec::with_capacity(self.rows * (self.cols - at));
+        for row in self.rows_iter() {
+            left.extend_from_slice(&row[..at]);
+            right.extend_from_slice(&row[at..]);
+        }
+        (
+            Matrix {
+                rows: self.rows,
+                cols: at,
+                data: left,
+            },
+            Matrix {
+                rows: self.rows,
+                cols: self.cols - at,
+                data: right,
+            },
+        )
+    }
+
+    /// Inserts a row before row `at`, shifting later rows down.
+    ///
+    /// A 0 x 0 matrix takes its column count from `row`.
+    ///
+    /// # Parameters
+    ///
+    /// - `at`: Index the new row will have. `at == rows` appends.
+    /// - `row`: Values of the new row.
+    ///
+    /// # Returns
+    ///
+    /// A Result that is an error if `at > rows` or `row` has the wrong length.
+    pub fn insert_row(&mut self, at: usize, row: &[f64]) -> Result<(), LinalgError> {
+        if at > self.rows {
+            return Err(LinalgError::IndexOutOfBounds {
+                index: at,
+                bound: self.rows + 1,
+            });
+        }
+        if self.rows == 0 && self.cols == 0 {
+            self.cols = row.len();
+        }
+        if row.len() != self.cols {
+            return Err(LinalgError::LengthMismatch {
+                expected: self.cols,
+                found: row.len(),
+            });
+        }
+        let start = at * self.cols;
+        self.data.splice(start..start, row.iter().copied());
+        self.rows += 1;
+        Ok(())
+    }
+
+    /// Inserts a column before column `at`, shifting later columns right.
+    ///
+    /// A 0 x 0 matrix takes its row count from `col`.
+    ///
+    /// # Parameters
+    ///
+    /// - `at`: Index the new column will have. `at == cols` appends.
+    /// - `col`: Values of the new column.
+    ///
+    /// # Returns
+    ///
+    /// A Result that is an error if `at > cols` or `col` has the wrong length.
+    pub fn insert_col(&mut self, at: usize, col: &[f64]) -> Result<(), LinalgError> {
+        if at > self.cols {
+            return Err(LinalgError::IndexOutOfBounds {
+                index: at,
+                bound: self.cols + 1,
+            });
+        }
+        if self.rows == 0 && self.cols == 0 {
+            self.rows = col.len();
+        }
+        if col.len() != self.rows {
+            return Err(LinalgError::LengthMismatch {
+                expected: self.rows,
+                found: col.len(),
+            });
+        }
+        let mut data = Vec::with_capacity(self.data.len() + self.rows);
+        for (i, &val) in col.iter().enumerate() {
+            let row = &self.data[i * self.cols..(i + 1) * self.cols];
+            data.extend_from_slice(&row[..at]);
+            data.push(val);
+            data.extend_from_slice(&row[at..]);
+        }
+        self.data = data;
+        self.cols += 1;
+        Ok(())
+    }
+
+    /// Appends a row below the last one, in amortized `O(cols)` time.
+    ///
+    /// A 0 x 0 matrix takes its column count from `row`.
+    ///
+    /// # Returns
+    ///
+    /// A Result that is an error if `row` has the wrong length.
+    pub fn push_row(&mut self, row: &[f64]) -> Result<(), LinalgError> {
+        self.insert_row(self.rows, row)
+    }
+
+    /// Appends a column to the right of the last one. This rebuilds the buffer in one pass.
+    ///
+    /// A 0 x 0 matrix takes its row count from `col`.
+    ///
+    /// # Returns
+    ///
+    /// A Result that is an error if `col` has the wrong length.
+    pub fn push_col(&mut self, col: &[f64]) -> Result<(), LinalgError> {
+        self.insert_col(self.cols, col)
+    }
+
+    /// Removes row `at`, shifting later rows up.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the removed values or an error if `at >= rows`.
+    pub fn remove_row(&mut self, at: usize) -> Result<Vec<f64>, LinalgError> {
+        if at >= self.rows {
+            return Err(LinalgError::IndexOutOfBounds {
+                index: at,
+                bound: self.rows,
+            });
+        }
+        let removed = self
+            .data
+            .drain(at * self.cols..(at + 1) * self.cols)
+            .collect();
+        self.rows -= 1;
+        Ok(removed)
+    }
+
+    /// Removes column `at`, shifting later columns left.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the removed values or an error if `at >= cols`.
+    pub fn remove_col(&mut self, at: usize) -> Result<Vec<f64>, LinalgError> {
+        if at >= self.cols {
+            return Err(LinalgError::IndexOutOfBounds {
+                index: at,
+                bound: self.cols,
+            });
+        }
+        let removed = (0..self.rows).map(|i| self[(i, at)]).collect();
+        let cols = self.cols;
+        let mut k = 0;
+        self.data.retain(|_| {
+            k += 1;
+            (k - 1) % cols != at
+        });
+        self.cols -= 1;
+        Ok(removed)
+    }
+
+    /// Gathers the listed rows into a new matrix, in the given order.
+    ///
+    /// Indices may repeat, so this can both reorder and duplicate rows.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the `idx.len()` x `cols` matrix or an error naming the first
+    /// out-of-range index.
+    pub fn select_rows(&self, idx: &[usize]) -> Result<Matrix, LinalgError> {
+        self.select(idx, &(0..self.cols).collect::<Vec<_>>())
+    }
+
+    /// Gathers the listed columns into a new matrix, in the given order.
+    ///
+    /// Indices may repeat, so this can both reorder and duplicate columns.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the `rows` x `idx.len()` matrix or an error naming the first
+    /// out-of-range index.
+    pub fn select_cols(&self, idx: &[usize]) -> Result<Matrix, LinalgError> {
+        self.select(&(0..self.rows).collect::<Vec<_>>(), idx)
+    }
+
+    /// Gathers the grid of elements at the listed rows and columns.
+    ///
+    /// # Parameters
+    ///
+    /// - `row_idx`: Rows to take, in order. Repeats are allowed.
+    /// - `col_idx`: Columns to take, in order. Repeats are allowed.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the `row_idx.len()` x `col_idx.len()` matrix whose `(a, b)`
+    /// element is `self[(row_idx[a], col_idx[b])]`, or an error naming the first out-of-range index.
+    pub fn select(&self, row_idx: &[usize], col_idx: &[usize]) -> Result<Matrix, LinalgError> {
+        for &(idx, bound) in &[(row_idx, self.rows), (col_idx, self.cols)] {
+            if let Some(&index) = idx.iter().find(|&&i| i >= bound) {
+                return Err(LinalgError::IndexOutOfBounds { index, bound });
+            }
+        }
+        Ok(Matrix::from_fn(row_idx.len(), col_idx.len(), |a, b| {
+            self[(row_idx[a], col_idx[b])]
+        }))
+    }
+
+    /// Deletes row `i` and column `j`, as used in cofactor expansion.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the `(rows - 1)` x `(cols - 1)` minor or an error if the
+    /// matrix has fewer than two rows or columns or either index is out of range.
+    pub fn minor(&self, i: usize, j: usize) -> Result<Matrix, LinalgError> {
+        if self.rows < 2 || self.cols < 2 {
+            return Err(LinalgError::InvalidArgument(format!(
+                "A minor needs at least two rows and columns. Shape: ({}, {})",
+                self.rows, self.cols
+            )));
+        }
+        for &(index, bound) in &[(i, self.rows), (j, self.cols)] {
+            if index >= bound {
+                return Err(LinalgError::IndexOutOfBounds { index, bound });
+            }
+        }
+        let mut data = Vec::with_capacity((self.rows - 1) * (self.cols - 1));
+        for (r, row) in self.rows_iter().enumerate() {
+            if r == i {
+                continue;
+            }
+            data.extend_from_slice(&row[..j]);
+            data.extend_from_slice(&row[j + 1..]);
+        }
+        Ok(Matrix {
+            rows: self.rows - 1,
+            cols: self.cols - 1,
+            data,
+        })
+    }
+
+    /// Copies a `rows` x `cols` block starting at `(row_offset, col_offset)` out of the matrix.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the block or an error if it would run past the edge of the
+    /// matrix.
+    pub fn get_block(
+        &self,
+        row_offset: usize,
+        col_offset: usize,
+        rows: usize,
+        cols: usize,
+    ) -> Result<Matrix, LinalgError> {
+        let row_end = block_end(row_offset, rows, self.rows)?;
+        let col_end = block_end(col_offset, cols, self.cols)?;
+        self.submatrix(row_offset..row_end, col_offset..col_end)
+    }
+
+    /// Overwrites the elements covered by `block` when its top-left corner is placed at
+    /// `(row_offset, col_offset)`.
+    ///
+    /// # Returns
+    ///
+    /// A Result that is an error if the block would run past the edge of the matrix, in which
+    /// case nothing is written. An empty block writes nothing and always succeeds.
+    pub fn set_block(
+        &mut self,
+        row_offset: usize,
+        col_offset: usize,
+        block: &Matrix,
+    ) -> Result<(), LinalgError> {
+        if block.rows == 0 || block.cols == 0 {
+            return Ok(());
+        }
+        let row_end = block_end(row_offset, block.rows, self.rows)?;
+        let col_end = block_end(col_offset, block.cols, self.cols)?;
+        check_range("Row", &(row_offset..row_end), self.rows)?;
+        check_range("Column", &(col_offset..col_end), self.cols)?;
+        for (i, row) in block.rows_iter().enumerate() {
+            let start = (row_offset + i) * self.cols + col_offset;
+            self.data[start..start + block.cols].copy_from_slice(row);
+        }
+        Ok(())
+    }
+
+    /// Changes the shape of the matrix in place, keeping the overlapping top-left block.
+    ///
+    /// # Parameters
+    ///
+    /// - `new_rows`: Row count after resizing. May be zero.
+    /// - `new_cols`: Column count after resizing. May be zero.
+    /// - `fill`: Value for cells outside the original matrix.
+    pub fn resize(&mut self, new_rows: usize, new_cols: usize, fill: f64) {
+        let keep_rows = self.rows.min(new_rows);
+        let keep_cols = self.cols.min(new_cols);
+        let mut data = Vec::with_capacity(new_rows * new_cols);
+        for i in 0..keep_rows {
+            data.extend_from_slice(&self.data[i * self.cols..i * self.cols + keep_cols]);
+            data.resize((i + 1) * new_cols, fill);
+        }
+        data.resize(new_rows * new_cols, fill);
+        self.rows = new_rows;
+        self.cols = new_cols;
+        self.data = data;
+    }
+
+    /// Copies a rectangular block out of the matrix.
+    ///
+    /// # Parameters
+    ///
+    /// - `rows`: Range of row indices to keep.
+    /// - `cols`: Range of column indices to keep.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the `rows.len()` x `cols.len()` block or an error if either
+    /// range is reversed or extends past the matrix. Empty ranges give an empty dimension.
+    pub fn submatrix(&self, rows: Range<usize>, cols: Range<usize>) -> Result<Matrix, LinalgError> {
+        check_range("Row", &rows, self.rows)?;
+        check_range("Column", &cols, self.cols)?;
+        let mut data = Vec::with_capacity(rows.len() * cols.len());
+        for i in rows.clone() {
+            data.extend_from_slice(
+                &self.data[i * self.cols + cols.start..i * self.cols + cols.end],
+            );
+        }
+        Ok(Matrix {
+            rows: rows.len(),
+            cols: cols.len(),
+            data,
+        })
+    }
+}
+
+// End of a block of `len` elements starting at `offset`, or an error if it does not fit in
+// `usize`, which no matrix dimension `bound` can reach.
+fn block_end(offset: usize, len: usize, bound: usize) -> Result<usize, LinalgError> {
+    offset
+        .checked_add(len)
+        .ok_or(LinalgError::IndexOutOfBounds {
+            index: offset,
+            bound,
+        })
+}
+
+pub(crate) fn check_range(
+    kind: &str,
+    range: &Range<usize>,
+    bound: usize,
+) -> Result<(), LinalgError> {
+    if range.start > range.end || range.end > bound {
+        return Err(LinalgError::InvalidArgument(format!(
+            "{} range {}..{} is not within 0..{}",
+            kind, range.start, range.end, bound
+        )));
+    }
+    Ok(())
+}
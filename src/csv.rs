@@ -0,0 +1,72 @@
+use std::io::{self, BufRead, BufReader, Read, Write};
+
+use crate::{LinalgError, Matrix};
+
+impl Matrix {
+    /// Reads a matrix from comma-separated values, one matrix row per line.
+    ///
+    /// The number of columns is taken from the first line. Blank lines are skipped and
+    /// whitespace around each value is ignored, except that input made up only of blank lines
+    /// is read as one empty row per line, so an `n` x 0 matrix survives a round trip through
+    /// `to_csv`.
+    ///
+    /// # Parameters
+    ///
+    /// - `reader`: Source of the CSV text.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the parsed matrix or an error if reading fails, a value is
+    /// not a number, or a row has a different number of values than the first.
+    pub fn from_csv(reader: impl Read) -> Result<Matrix, LinalgError> {
+        let mut rows = 0;
+        let mut cols = 0;
+        let mut blank_lines = 0;
+        let mut data = Vec::new();
+        for (n, line) in BufReader::new(reader).lines().enumerate() {
+            let line = line.map_err(|e| LinalgError::Parse(e.to_string()))?;
+            if line.trim().is_empty() {
+                blank_lines += 1;
+                continue;
+            }
+            let start = data.len();
+            for field in line.split(',') {
+                let val = field.trim().parse::<f64>().map_err(|_| {
+                    LinalgError::Parse(format!("Invalid number {:?} on line {}", field, n + 1))
+                })?;
+                data.push(val);
+            }
+            let found = data.len() - start;
+            if rows == 0 {
+                cols = found;
+            } else if found != cols {
+                return Err(LinalgError::InconsistentRow {
+                    row: rows,
+                    expected: cols,
+                    found,
+                });
+            }
+            rows += 1;
+        }
+        if rows == 0 {
+            rows = blank_lines;
+        }
+        Ok(Matrix { rows, cols, data })
+    }
+
+    /// Writes the matrix as comma-separated values, one matrix row per line.
+    ///
+    /// Values are written with enough digits to be read back exactly by `from_csv`. A matrix
+    /// with no rows writes nothing, so it reads back as 0 x 0 whatever its column count.
+    ///
+    /// # Parameters
+    ///
+    /// - `writer`: Destination of the CSV text.
+    pub fn to_csv(&self, mut writer: impl Write) -> io::Result<()> {
+        for row in self.rows_iter() {
+            let fields: Vec<String> = row.iter().map(|x| x.to_string()).collect();
+            writeln!(writer, "{}", fields.join(","))?;
+        }
+        Ok(())
+    }
+}
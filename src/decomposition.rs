@@ -0,0 +1,850 @@
+use crate::{LinalgError, Matrix};
+
+/// Pivots with an absolute value below this are treated as zero.
+const PIVOT_TOL: f64 = 1e-12;
+
+/// Sweep limit and relative tolerance for the Jacobi eigensolver when it is
+/// used internally.
+const JACOBI_SWEEPS: usize = 100;
+const JACOBI_TOL: f64 = 1e-14;
+
+/// LU factorization of a square matrix with partial pivoting, for reuse across solves.
+///
+/// Built by `Matrix::lu_factor`. The multipliers of `L` are stored below the
+/// diagonal and `U` on and above it; row `k` came from row `perm[k]` of the
+/// original matrix.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lu {
+    lu: Matrix,
+    perm: Vec<usize>,
+    sign: f64,
+    pub(crate) singular: bool,
+}
+
+/// Outcome of a solve with iterative refinement.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RefinedSolveResult {
+    /// The refined solution, with the same shape as the right-hand side.
+    pub solution: Matrix,
+    /// Number of accepted refinement steps after the initial solve.
+    pub iterations: usize,
+    /// Frobenius norm of the residual `b - A x` of `solution`.
+    pub residual: f64,
+}
+
+/// A thin singular value decomposition `a = u * diag(s) * vt`.
+///
+/// For an `m` x `n` matrix of rank at most `r`, `u` is `m` x `r` with orthonormal columns,
+/// `s` holds the `r` singular values in descending order and `vt` is `r` x `n` with
+/// orthonormal rows.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Svd {
+    pub u: Matrix,
+    pub s: Vec<f64>,
+    pub vt: Matrix,
+}
+
+impl Svd {
+    /// Multiplies the factors back together.
+    ///
+    /// # Returns
+    ///
+    /// The matrix `u * diag(s) * vt`.
+    pub fn reconstruct(&self) -> Matrix {
+        let us = Matrix::from_fn(self.u.rows, self.u.cols, |i, j| self.u[(i, j)] * self.s[j]);
+        &us * &self.vt
+    }
+}
+
+impl Lu {
+    /// Solves `A * x = b` for the matrix `A` these factors came from.
+    ///
+    /// # Parameters
+    ///
+    /// - `b`: Right-hand side with as many rows as `A`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the solution `x`, with the same shape as `b`, or an error if
+    /// the row counts differ.
+    pub fn solve(&self, b: &Matrix) -> Result<Matrix, LinalgError> {
+        self.check_rhs(b)?;
+        Ok(self.substitute(b))
+    }
+
+    /// Solves `A * x = b` and improves the solution by iterative refinement.
+    ///
+    /// Each step computes the residual `r = b - A x` with compensated
+    /// summation, solves `A d = r` with the stored factors and replaces `x` by
+    /// `x + d`. Refinement stops once a step no longer reduces the residual,
+    /// keeping the last improving solution, or after `max_iter` steps.
+    ///
+    /// # Parameters
+    ///
+    /// - `a`: The matrix these factors came from.
+    /// - `b`: Right-hand side with as many rows as `a`.
+    /// - `max_iter`: Maximum number of refinement steps.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the refined solution with its residual and step count, or an
+    /// error if `a` does not have the factored shape or the row counts differ.
+    pub fn solve_refined(
+        &self,
+        a: &Matrix,
+        b: &Matrix,
+        max_iter: usize,
+    ) -> Result<RefinedSolveResult, LinalgError> {
+        if a.shape() != self.lu.shape() {
+            return Err(LinalgError::ShapeMismatch {
+                expected: self.lu.shape(),
+                found: a.shape(),
+            });
+        }
+        self.check_rhs(b)?;
+        let residual_of = |x: &Matrix| residual(a, x, b);
+
+        let mut x = self.substitute(b);
+        let mut r = residual_of(&x);
+        let mut norm = r.frobenius_norm();
+        let mut iterations = 0;
+        while iterations < max_iter && norm > 0. {
+            let next = x.clone() + self.substitute(&r);
+            let next_r = residual_of(&next);
+            let next_norm = next_r.frobenius_norm();
+            if next_norm >= norm {
+                break;
+            }
+            x = next;
+            r = next_r;
+            norm = next_norm;
+            iterations += 1;
+        }
+        Ok(RefinedSolveResult {
+            solution: x,
+            iterations,
+            residual: norm,
+        })
+    }
+
+    fn check_rhs(&self, b: &Matrix) -> Result<(), LinalgError> {
+        if b.rows != self.lu.rows {
+            return Err(LinalgError::ShapeMismatch {
+                expected: (self.lu.rows, b.cols),
+                found: b.shape(),
+            });
+        }
+        Ok(())
+    }
+
+    // Forward and back substitution with the stored factors, without checks.
+    pub(crate) fn substitute(&self, b: &Matrix) -> Matrix {
+        let n = self.lu.rows;
+        let mut x = Matrix::from_fn(n, b.cols, |i, j| b[(self.perm[i], j)]);
+        for col in 0..b.cols {
+            for i in 0..n {
+                let mut sum = x[(i, col)];
+                for k in 0..i {
+                    sum -= self.lu[(i, k)] * x[(k, col)];
+                }
+                x[(i, col)] = sum;
+            }
+            for i in (0..n).rev() {
+                let mut sum = x[(i, col)];
+                for k in (i + 1)..n {
+                    sum -= self.lu[(i, k)] * x[(k, col)];
+                }
+                x[(i, col)] = sum / self.lu[(i, i)];
+            }
+        }
+        x
+    }
+}
+
+impl Matrix {
+    /// Computes the determinant of a square matrix.
+    ///
+    /// Uses Gaussian elimination with partial pivoting, flipping the sign for
+    /// every row swap. Singular matrices give `0.0`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the determinant or an error if the matrix is not square.
+    pub fn determinant(&self) -> Result<f64, LinalgError> {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquare);
+        }
+        let factors = self.lu_factors();
+        if factors.singular {
+            return Ok(0.);
+        }
+        Ok((0..self.rows).fold(factors.sign, |det, i| det * factors.lu[(i, i)]))
+    }
+
+    /// Computes the sign and the natural logarithm of the absolute value of the determinant.
+    ///
+    /// Sums the logarithms of the LU pivots instead of multiplying them, so the
+    /// result stays finite for large matrices whose determinant would overflow
+    /// or underflow.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either `(sign, logabsdet)` such that the determinant is
+    /// `sign * logabsdet.exp()`, or an error if the matrix is not square. Singular matrices give
+    /// `(0.0, f64::NEG_INFINITY)`.
+    pub fn logdet(&self) -> Result<(f64, f64), LinalgError> {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquare);
+        }
+        let factors = self.lu_factors();
+        if factors.singular {
+            return Ok((0., f64::NEG_INFINITY));
+        }
+        Ok((0..self.rows).fold((factors.sign, 0.), |(sign, log), i| {
+            let pivot = factors.lu[(i, i)];
+            (sign * pivot.signum(), log + pivot.abs().ln())
+        }))
+    }
+
+    /// Factors a square matrix with partial pivoting so that several systems can be solved
+    /// with it.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the factorization or an error if the matrix is not square or
+    /// is singular.
+    pub fn lu_factor(&self) -> Result<Lu, LinalgError> {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquare);
+        }
+        let factors = self.lu_factors();
+        if factors.singular {
+            return Err(LinalgError::Singular);
+        }
+        Ok(factors)
+    }
+
+    /// Computes the LU decomposition of a square matrix with partial pivoting.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing `(p, l, u)` where `p` is a permutation matrix, `l` is lower triangular
+    /// with a unit diagonal and `u` is upper triangular such that `p * l * u == self`, or an error
+    /// if the matrix is not square.
+    pub fn lu(&self) -> Result<(Matrix, Matrix, Matrix), LinalgError> {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquare);
+        }
+        let n = self.rows;
+        let factors = self.lu_factors();
+        let mut p = Matrix::zeros(n, n);
+        for (k, &row) in factors.perm.iter().enumerate() {
+            p[(row, k)] = 1.;
+        }
+        let l = Matrix::from_fn(n, n, |i, j| match i.cmp(&j) {
+            std::cmp::Ordering::Greater => factors.lu[(i, j)],
+            std::cmp::Ordering::Equal => 1.,
+            std::cmp::Ordering::Less => 0.,
+        });
+        let u = Matrix::from_fn(n, n, |i, j| if i <= j { factors.lu[(i, j)] } else { 0. });
+        Ok((p, l, u))
+    }
+
+    /// Solves the linear system `self * x = b`.
+    ///
+    /// Uses LU decomposition with partial pivoting followed by forward and
+    /// back substitution. Each column of `b` is treated as a separate
+    /// right-hand side.
+    ///
+    /// # Parameters
+    ///
+    /// - `b`: Right-hand side with as many rows as `self`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the solution `x`, with the same shape as `b`, or an error if
+    /// `self` is not square, the row counts differ or the system is singular.
+    pub fn solve(&self, b: &Matrix) -> Result<Matrix, LinalgError> {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquare);
+        }
+        if b.rows != self.rows {
+            return Err(LinalgError::ShapeMismatch {
+                expected: (self.rows, b.cols),
+                found: b.shape(),
+            });
+        }
+        Ok(self.lu_factor()?.substitute(b))
+    }
+
+    /// Checks whether the matrix is symmetric positive definite.
+    ///
+    /// Attempts a Cholesky factorization `self = L Lᵀ` and reports whether
+    /// every pivot is positive, which is cheaper and more reliable than
+    /// computing the eigenvalues.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the matrix is square, symmetric up to rounding and its Cholesky factorization
+    /// succeeds.
+    pub fn is_positive_definite(&self) -> bool {
+        self.is_symmetric(PIVOT_TOL * self.frobenius_norm()) && self.cholesky_factor().is_some()
+    }
+
+    /// Computes the inverse of a square matrix.
+    ///
+    /// Uses Gauss-Jordan elimination with partial pivoting on the augmented
+    /// system `[A | I]`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the inverse or an error if the matrix is not square or is singular.
+    pub fn inverse(&self) -> Result<Matrix, LinalgError> {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquare);
+        }
+        let n = self.rows;
+        let identity = Matrix::identity(n);
+        let mut aug = Matrix::from_fn(n, 2 * n, |i, j| {
+            if j < n {
+                self[(i, j)]
+            } else {
+                identity[(i, j - n)]
+            }
+        });
+        let max_abs = self.data.iter().fold(0., |m: f64, x| m.max(x.abs()));
+        let tol = n as f64 * f64::EPSILON * max_abs;
+
+        for k in 0..n {
+            let pivot_row = aug.pivot_row(k, k);
+            if aug[(pivot_row, k)].abs() <= tol {
+                return Err(LinalgError::Singular);
+            }
+            aug.swap_rows(k, pivot_row);
+
+            let pivot = aug[(k, k)];
+            for j in 0..2 * n {
+                aug[(k, j)] /= pivot;
+            }
+            for i in 0..n {
+                if i == k {
+                    continue;
+                }
+                let factor = aug[(i, k)];
+                if factor != 0. {
+                    for j in 0..2 * n {
+                        aug[(i, j)] -= factor * aug[(k, j)];
+                    }
+                }
+            }
+        }
+
+        Ok(Matrix::from_fn(n, n, |i, j| aug[(i, j + n)]))
+    }
+
+    /// Solves a tridiagonal system with the Thomas algorithm in `O(n)` time.
+    ///
+    /// The algorithm does not pivot, so it is only guaranteed to succeed for diagonally dominant or
+    /// symmetric positive definite systems. Use `is_tridiagonal` to check whether a dense matrix can
+    /// be solved this way.
+    ///
+    /// # Parameters
+    ///
+    /// - `sub`: The `n - 1` elements below the diagonal, from the top.
+    /// - `diag`: The `n` diagonal elements.
+    /// - `sup`: The `n - 1` elements above the diagonal, from the top.
+    /// - `b`: The `n` elements of the right-hand side.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the solution or an error if a slice has the wrong length or a
+    /// pivot is zero.
+    pub fn solve_tridiagonal(
+        sub: &[f64],
+        diag: &[f64],
+        sup: &[f64],
+        b: &[f64],
+    ) -> Result<Vec<f64>, LinalgError> {
+        let n = diag.len();
+        for (slice, expected) in [
+            (sub, n.saturating_sub(1)),
+            (sup, n.saturating_sub(1)),
+            (b, n),
+        ] {
+            if slice.len() != expected {
+                return Err(LinalgError::LengthMismatch {
+                    expected,
+                    found: slice.len(),
+                });
+            }
+        }
+        let max_abs = [sub, diag, sup]
+            .iter()
+            .flat_map(|slice| slice.iter())
+            .fold(0., |m: f64, x| m.max(x.abs()));
+        let tol = n as f64 * f64::EPSILON * max_abs;
+        let mut c = Vec::with_capacity(n);
+        let mut x = Vec::with_capacity(n);
+        for i in 0..n {
+            let (below, prev_c, prev_x) = if i == 0 {
+                (0., 0., 0.)
+            } else {
+                (sub[i - 1], c[i - 1], x[i - 1])
+            };
+            let pivot = diag[i] - below * prev_c;
+            if pivot.abs() <= tol {
+                return Err(LinalgError::InvalidArgument(format!(
+                    "Zero pivot in row {} of the tridiagonal system; it may need pivoting",
+                    i
+                )));
+            }
+            c.push(if i + 1 < n { sup[i] / pivot } else { 0. });
+            x.push((b[i] - below * prev_x) / pivot);
+        }
+        for i in (0..n.saturating_sub(1)).rev() {
+            x[i] -= c[i] * x[i + 1];
+        }
+        Ok(x)
+    }
+
+    /// Solves `self * x = b` by forward substitution, treating `self` as lower triangular.
+    ///
+    /// Elements above the diagonal are never read.
+    ///
+    /// # Parameters
+    ///
+    /// - `b`: Right-hand side with as many rows as `self`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the solution, with the same shape as `b`, or an error if
+    /// `self` is not square, the row counts differ or a diagonal element is zero.
+    pub fn solve_lower(&self, b: &Matrix) -> Result<Matrix, LinalgError> {
+        self.check_triangular_system(b)?;
+        forward_substitute(self, b)
+    }
+
+    /// Solves `self * x = b` by back substitution, treating `self` as upper triangular.
+    ///
+    /// Elements below the diagonal are never read.
+    ///
+    /// # Parameters
+    ///
+    /// - `b`: Right-hand side with as many rows as `self`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the solution, with the same shape as `b`, or an error if
+    /// `self` is not square, the row counts differ or a diagonal element is zero.
+    pub fn solve_upper(&self, b: &Matrix) -> Result<Matrix, LinalgError> {
+        self.check_triangular_system(b)?;
+        back_substitute(self, b)
+    }
+
+    /// Solves `self * x ≈ b` in the least-squares sense.
+    ///
+    /// Uses the thin QR decomposition `self = QR` and solves `R x = Qᵀ b` by
+    /// back substitution, which avoids squaring the condition number as the
+    /// normal equations would. Each column of `b` is a separate right-hand side.
+    ///
+    /// # Parameters
+    ///
+    /// - `b`: Right-hand side with as many rows as `self`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the `cols` x `b.cols` solution or an error if the row counts
+    /// differ, `self` has fewer rows than columns, or its columns are linearly dependent.
+    pub fn lstsq(&self, b: &Matrix) -> Result<Matrix, LinalgError> {
+        if b.rows != self.rows {
+            return Err(LinalgError::ShapeMismatch {
+                expected: (self.rows, b.cols),
+                found: b.shape(),
+            });
+        }
+        let (q, r) = self.qr()?;
+        back_substitute(&r, &(&q.transpose() * b))
+    }
+
+    /// Computes the Moore-Penrose pseudoinverse.
+    ///
+    /// Built from the singular value decomposition as `V Σ⁺ Uᵀ`, so
+    /// rank-deficient matrices are handled as well as full-rank ones and the
+    /// condition number is not squared as it would be by going through `AᵀA`.
+    ///
+    /// # Parameters
+    ///
+    /// - `tol`: Singular values at most `tol` times the largest one are treated as zero.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the `cols` x `rows` pseudoinverse or an error if the
+    /// singular value decomposition does not converge.
+    pub fn pseudo_inverse(&self, tol: f64) -> Result<Matrix, LinalgError> {
+        let Svd { u, s, vt } = self.svd()?;
+        let cutoff = tol * s.first().copied().unwrap_or(0.);
+        let inv: Vec<f64> = s
+            .iter()
+            .map(|&sigma| {
+                if sigma > cutoff && sigma > 0. {
+                    1. / sigma
+                } else {
+                    0.
+                }
+            })
+            .collect();
+        let v_scaled = Matrix::from_fn(vt.cols, vt.rows, |i, k| vt[(k, i)] * inv[k]);
+        Ok(&v_scaled * &u.transpose())
+    }
+
+    /// Computes the thin singular value decomposition.
+    ///
+    /// Uses the one-sided Jacobi method, which rotates pairs of columns until
+    /// they are mutually orthogonal. It works on the matrix itself rather than
+    /// on `AᵀA`, so small singular values keep their relative accuracy. Wide
+    /// matrices are decomposed through their transpose.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the `Svd` with `min(rows, cols)` singular values, or an error
+    /// if the rotations have not converged. Columns of `u` belonging to a zero singular value
+    /// are left as zero.
+    pub fn svd(&self) -> Result<Svd, LinalgError> {
+        if self.rows < self.cols {
+            let svd = self.clone().transpose().svd()?;
+            return Ok(Svd {
+                u: svd.vt.transpose(),
+                s: svd.s,
+                vt: svd.u.transpose(),
+            });
+        }
+        let n = self.cols;
+        let mut u = self.clone();
+        let mut v = Matrix::identity(n);
+
+        let mut converged = false;
+        for _ in 0..JACOBI_SWEEPS {
+            let mut rotated = false;
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    let (mut alpha, mut beta, mut gamma) = (0., 0., 0.);
+                    for i in 0..u.rows {
+                        let (x, y) = (u[(i, p)], u[(i, q)]);
+                        alpha += x * x;
+                        beta += y * y;
+                        gamma += x * y;
+                    }
+                    if gamma.abs() <= JACOBI_TOL * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+                    let zeta = (beta - alpha) / (2. * gamma);
+                    let t = zeta.signum() / (zeta.abs() + zeta.hypot(1.));
+                    let c = 1. / t.hypot(1.);
+                    let s = t * c;
+                    u.apply_givens_right(p, q, c, s);
+                    v.apply_givens_right(p, q, c, s);
+                }
+            }
+            if !rotated {
+                converged = true;
+                break;
+            }
+        }
+        if !converged {
+            return Err(LinalgError::NotConverged {
+                iterations: JACOBI_SWEEPS,
+            });
+        }
+
+        let norms: Vec<f64> = (0..n)
+            .map(|j| (0..u.rows).map(|i| u[(i, j)].powi(2)).sum::<f64>().sqrt())
+            .collect();
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&x, &y| norms[y].total_cmp(&norms[x]));
+        let s: Vec<f64> = order.iter().map(|&j| norms[j]).collect();
+        let u = Matrix::from_fn(u.rows, n, |i, k| {
+            let j = order[k];
+            if norms[j] > 0. {
+                u[(i, j)] / norms[j]
+            } else {
+                0.
+            }
+        });
+        let vt = Matrix::from_fn(n, n, |k, i| v[(i, order[k])]);
+        Ok(Svd { u, s, vt })
+    }
+
+    /// Computes the thin QR decomposition using Householder reflections.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing `(q, r)` where `q` is `rows` x `cols` with orthonormal columns and `r` is
+    /// `cols` x `cols` upper triangular such that `q * r == self`, or an error if the matrix
+    /// has fewer rows than columns.
+    pub fn qr(&self) -> Result<(Matrix, Matrix), LinalgError> {
+        let (m, n) = self.shape();
+        if m < n {
+            return Err(LinalgError::InvalidArgument(format!(
+                "QR decomposition requires at least as many rows as columns. Shape: ({}, {})",
+                m, n
+            )));
+        }
+        let mut r = self.clone();
+        let mut reflectors = Vec::with_capacity(n);
+        let tol = f64::EPSILON * self.frobenius_norm();
+
+        for k in 0..n {
+            let mut v: Vec<f64> = (k..m).map(|i| r[(i, k)]).collect();
+            let below = v[1..].iter().map(|x| x * x).sum::<f64>();
+            if below.sqrt() <= tol {
+                reflectors.push(None);
+                continue;
+            }
+            let norm = (v[0] * v[0] + below).sqrt();
+            let alpha = if v[0] > 0. { -norm } else { norm };
+            v[0] -= alpha;
+            let v_norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+            for x in &mut v {
+                *x /= v_norm;
+            }
+            for j in k..n {
+                let dot: f64 = (k..m).map(|i| v[i - k] * r[(i, j)]).sum();
+                for i in k..m {
+                    r[(i, j)] -= 2. * v[i - k] * dot;
+                }
+            }
+            reflectors.push(Some(v));
+        }
+
+        let mut q = Matrix::from_fn(m, n, |i, j| if i == j { 1. } else { 0. });
+        for (k, v) in reflectors.iter().enumerate().rev() {
+            if let Some(v) = v {
+                for j in 0..n {
+                    let dot: f64 = (k..m).map(|i| v[i - k] * q[(i, j)]).sum();
+                    for i in k..m {
+                        q[(i, j)] -= 2. * v[i - k] * dot;
+                    }
+                }
+            }
+        }
+
+        let r = Matrix::from_fn(n, n, |i, j| if i <= j { r[(i, j)] } else { 0. });
+        Ok((q, r))
+    }
+
+    /// Computes the Givens rotation that zeroes `b` in the vector `(a, b)`.
+    ///
+    /// # Returns
+    ///
+    /// A tuple `(c, s, r)` such that `c * a + s * b == r` and `-s * a + c * b == 0`.
+    /// When `a` and `b` are both zero the identity rotation `(1, 0, 0)` is returned.
+    pub fn givens_coeffs(a: f64, b: f64) -> (f64, f64, f64) {
+        if b == 0. {
+            return (1., 0., a);
+        }
+        let r = a.hypot(b);
+        (a / r, b / r, r)
+    }
+
+    /// Creates an `n` x `n` Givens rotation acting on coordinates `i` and `j`.
+    ///
+    /// The result is the identity except for `(i, i) = (j, j) = c`, `(i, j) = s`
+    /// and `(j, i) = -s`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i == j` or either index is not less than `n`.
+    pub fn givens(n: usize, i: usize, j: usize, c: f64, s: f64) -> Matrix {
+        check_rotation_indices(n, i, j);
+        let mut g = Matrix::identity(n);
+        g[(i, i)] = c;
+        g[(j, j)] = c;
+        g[(i, j)] = s;
+        g[(j, i)] = -s;
+        g
+    }
+
+    /// Applies a Givens rotation from the left in place, equivalent to
+    /// `*self = Matrix::givens(rows, i, j, c, s) * self` but only touching rows `i` and `j`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i == j` or either index is out of range.
+    pub fn apply_givens_left(&mut self, i: usize, j: usize, c: f64, s: f64) {
+        check_rotation_indices(self.rows, i, j);
+        for k in 0..self.cols {
+            let (x, y) = (self[(i, k)], self[(j, k)]);
+            self[(i, k)] = c * x + s * y;
+            self[(j, k)] = -s * x + c * y;
+        }
+    }
+
+    /// Applies a Givens rotation from the right in place, equivalent to
+    /// `*self = self * Matrix::givens(cols, i, j, c, s)` but only touching columns `i` and `j`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i == j` or either index is out of range.
+    pub fn apply_givens_right(&mut self, i: usize, j: usize, c: f64, s: f64) {
+        check_rotation_indices(self.cols, i, j);
+        for k in 0..self.rows {
+            let (x, y) = (self[(k, i)], self[(k, j)]);
+            self[(k, i)] = c * x - s * y;
+            self[(k, j)] = s * x + c * y;
+        }
+    }
+
+    // Lower triangular `L` with `self = L Lᵀ`, reading only the lower triangle of
+    // `self`, or `None` if a pivot is not positive.
+    fn cholesky_factor(&self) -> Option<Matrix> {
+        let n = self.rows;
+        let mut l = Matrix::zeros(n, n);
+        for j in 0..n {
+            let pivot = self[(j, j)] - (0..j).map(|k| l[(j, k)] * l[(j, k)]).sum::<f64>();
+            if pivot.is_nan() || pivot <= 0. {
+                return None;
+            }
+            l[(j, j)] = pivot.sqrt();
+            for i in (j + 1)..n {
+                let sum: f64 = (0..j).map(|k| l[(i, k)] * l[(j, k)]).sum();
+                l[(i, j)] = (self[(i, j)] - sum) / l[(j, j)];
+            }
+        }
+        Some(l)
+    }
+
+    pub(crate) fn lu_factors(&self) -> Lu {
+        let n = self.rows;
+        let mut lu = self.clone();
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut sign = 1.;
+        let mut singular = false;
+        // Relative to the size of the entries, so badly scaled but well
+        // conditioned matrices are not mistaken for singular ones.
+        let max_abs = self.data.iter().fold(0., |m: f64, x| m.max(x.abs()));
+        let tol = n as f64 * f64::EPSILON * max_abs;
+
+        for k in 0..n {
+            let pivot_row = lu.pivot_row(k, k);
+            if pivot_row != k {
+                lu.swap_rows(k, pivot_row);
+                perm.swap(k, pivot_row);
+                sign = -sign;
+            }
+            let pivot = lu[(k, k)];
+            if pivot.abs() <= tol {
+                singular = true;
+                continue;
+            }
+            for i in (k + 1)..n {
+                let factor = lu[(i, k)] / pivot;
+                lu[(i, k)] = factor;
+                for j in (k + 1)..n {
+                    lu[(i, j)] -= factor * lu[(k, j)];
+                }
+            }
+        }
+
+        Lu {
+            lu,
+            perm,
+            sign,
+            singular,
+        }
+    }
+
+    fn check_triangular_system(&self, b: &Matrix) -> Result<(), LinalgError> {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquare);
+        }
+        if b.rows != self.rows {
+            return Err(LinalgError::ShapeMismatch {
+                expected: (self.rows, b.cols),
+                found: b.shape(),
+            });
+        }
+        Ok(())
+    }
+
+    // Index of the row at or below `start` with the largest magnitude in `col`.
+    fn pivot_row(&self, start: usize, col: usize) -> usize {
+        (start..self.rows)
+            .max_by(|&x, &y| self[(x, col)].abs().total_cmp(&self[(y, col)].abs()))
+            .unwrap()
+    }
+}
+
+// Computes `b - a * x` with compensated (Dot2) summation, so the residual is
+// accurate to about twice working precision. A residual rounded in working
+// precision would be mostly noise once `x` is close to the solution.
+fn residual(a: &Matrix, x: &Matrix, b: &Matrix) -> Matrix {
+    Matrix::from_fn(b.rows, b.cols, |i, j| {
+        let (mut sum, mut err) = (b[(i, j)], 0.);
+        for k in 0..a.cols {
+            let p = -a[(i, k)] * x[(k, j)];
+            let p_err = (-a[(i, k)]).mul_add(x[(k, j)], -p);
+            let t = sum + p;
+            let z = t - sum;
+            err += (sum - (t - z)) + (p - z) + p_err;
+            sum = t;
+        }
+        sum + err
+    })
+}
+
+// Whether a diagonal element of the triangular matrix `t` is zero relative to
+// the largest one, so that substitution with it would be meaningless.
+fn has_negligible_diagonal(t: &Matrix) -> bool {
+    let n = t.rows;
+    let max_diag = (0..n).fold(0., |m: f64, i| m.max(t[(i, i)].abs()));
+    let tol = n as f64 * f64::EPSILON * max_diag;
+    (0..n).any(|i| t[(i, i)].abs() <= tol)
+}
+
+// Solves `l * x = b` for lower triangular `l`, ignoring anything above the diagonal.
+fn forward_substitute(l: &Matrix, b: &Matrix) -> Result<Matrix, LinalgError> {
+    let n = l.rows;
+    if has_negligible_diagonal(l) {
+        return Err(LinalgError::Singular);
+    }
+    let mut x = b.clone();
+    for col in 0..b.cols {
+        for i in 0..n {
+            let mut sum = x[(i, col)];
+            for k in 0..i {
+                sum -= l[(i, k)] * x[(k, col)];
+            }
+            x[(i, col)] = sum / l[(i, i)];
+        }
+    }
+    Ok(x)
+}
+
+// Solves `u * x = b` for upper triangular `u`, ignoring anything below the diagonal.
+fn back_substitute(u: &Matrix, b: &Matrix) -> Result<Matrix, LinalgError> {
+    let n = u.rows;
+    if has_negligible_diagonal(u) {
+        return Err(LinalgError::Singular);
+    }
+    let mut x = b.clone();
+    for col in 0..b.cols {
+        for i in (0..n).rev() {
+            let mut sum = x[(i, col)];
+            for k in (i + 1)..n {
+                sum -= u[(i, k)] * x[(k, col)];
+            }
+            x[(i, col)] = sum / u[(i, i)];
+        }
+    }
+    Ok(x)
+}
+
+fn check_rotation_indices(n: usize, i: usize, j: usize) {
+    if i == j || i >= n || j >= n {
+        panic!(
+            "Givens rotation needs two distinct indices below {} but got ({}, {}).",
+            n, i, j
+        );
+    }
+}
@@ -0,0 +1,257 @@
+use crate::{LinalgError, Matrix};
+
+impl Matrix {
+    /// Computes the eigenvalues and eigenvectors of a symmetric matrix.
+    ///
+    /// Uses the cyclic Jacobi method: each sweep applies one rotation per
+    /// off-diagonal pair, and iteration stops once the off-diagonal part is
+    /// small relative to the whole matrix.
+    ///
+    /// # Parameters
+    ///
+    /// - `max_iter`: Maximum number of sweeps.
+    /// - `tol`: Relative tolerance, used both for the symmetry check and for convergence.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the eigenvalues in ascending order and a matrix whose columns
+    /// are the matching unit eigenvectors, or an error if the matrix is not square, not
+    /// symmetric, or has not converged after `max_iter` sweeps.
+    pub fn symmetric_eig(
+        &self,
+        max_iter: usize,
+        tol: f64,
+    ) -> Result<(Vec<f64>, Matrix), LinalgError> {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquare);
+        }
+        let scale = self.frobenius_norm();
+        if !self.is_symmetric(tol * scale) {
+            return Err(LinalgError::InvalidArgument(
+                "Matrix is not symmetric".to_string(),
+            ));
+        }
+        let n = self.rows;
+        let mut a = self.clone();
+        let mut v = Matrix::identity(n);
+
+        let mut converged = false;
+        for _ in 0..=max_iter {
+            if off_diagonal_norm(&a) <= tol * scale {
+                converged = true;
+                break;
+            }
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    if a[(p, q)] == 0. {
+                        continue;
+                    }
+                    let theta = (a[(q, q)] - a[(p, p)]) / (2. * a[(p, q)]);
+                    let t = theta.signum() / (theta.abs() + theta.hypot(1.));
+                    let c = 1. / t.hypot(1.);
+                    let s = t * c;
+                    a.apply_givens_right(p, q, c, s);
+                    a.apply_givens_left(p, q, c, -s);
+                    a[(p, q)] = 0.;
+                    a[(q, p)] = 0.;
+                    v.apply_givens_right(p, q, c, s);
+                }
+            }
+        }
+        if !converged {
+            return Err(LinalgError::NotConverged {
+                iterations: max_iter,
+            });
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&x, &y| a[(x, x)].total_cmp(&a[(y, y)]));
+        let values = order.iter().map(|&i| a[(i, i)]).collect();
+        Ok((values, v.permute_cols(&order)))
+    }
+
+    /// Estimates the largest-magnitude eigenvalue and its eigenvector by power iteration.
+    ///
+    /// Starting from a fixed non-uniform vector, repeatedly multiplies by the
+    /// matrix and normalizes, using the Rayleigh quotient as the eigenvalue
+    /// estimate.
+    ///
+    /// # Parameters
+    ///
+    /// - `iters`: Maximum number of matrix-vector products.
+    /// - `tol`: Iteration stops once the residual `|A v - λ v|` is at most `tol` times `|λ|`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the eigenvalue and a unit eigenvector, or an error if the
+    /// matrix is not square, is empty, or the estimate has not converged after `iters` steps.
+    pub fn power_iteration(&self, iters: usize, tol: f64) -> Result<(f64, Vec<f64>), LinalgError> {
+        self.check_eigen_input()?;
+        power_method(self.rows, |v| self.mul_vec(v).unwrap(), tol, iters)
+            .map_err(|_| LinalgError::NotConverged { iterations: iters })
+    }
+
+    /// Finds the largest-magnitude eigenvalue and its eigenvector by power iteration.
+    ///
+    /// The vector is normalized every step and the eigenvalue is estimated by
+    /// the Rayleigh quotient. When two eigenvalues of largest magnitude have
+    /// opposite signs the iteration cannot settle on either, and an error is
+    /// returned.
+    ///
+    /// # Parameters
+    ///
+    /// - `tol`: Iteration stops once the residual `|A v - λ v|` is at most `tol` times `|λ|`.
+    /// - `max_iter`: Maximum number of matrix-vector products.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the eigenvalue and a unit eigenvector, or an error if the
+    /// matrix is not square or is empty, or one carrying the last estimate if the iteration has
+    /// not converged.
+    pub fn dominant_eigenpair(
+        &self,
+        tol: f64,
+        max_iter: usize,
+    ) -> Result<(f64, Vec<f64>), LinalgError> {
+        self.check_eigen_input()?;
+        power_method(self.rows, |v| self.mul_vec(v).unwrap(), tol, max_iter).map_err(|estimate| {
+            LinalgError::EigenNotConverged {
+                iterations: max_iter,
+                estimate,
+            }
+        })
+    }
+
+    /// Finds the smallest-magnitude eigenvalue and its eigenvector by inverse iteration.
+    ///
+    /// The matrix is LU-factored once and each step solves with the factors,
+    /// which is power iteration on the inverse.
+    ///
+    /// # Parameters
+    ///
+    /// - `tol`: Iteration stops once the residual `|B v - μ v|` of the inverse iteration is at
+    ///   most `tol` times `|μ|`, where `B` is the inverse of the shifted matrix and `μ` its
+    ///   Rayleigh quotient.
+    /// - `max_iter`: Maximum number of solves.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the eigenvalue and a unit eigenvector, or an error if the
+    /// matrix is not square, is empty or is singular, or one carrying the last estimate if the
+    /// iteration has not converged.
+    pub fn smallest_eigenpair(
+        &self,
+        tol: f64,
+        max_iter: usize,
+    ) -> Result<(f64, Vec<f64>), LinalgError> {
+        self.eigenpair_near(0., tol, max_iter)
+    }
+
+    /// Finds the eigenvalue closest to `sigma` and its eigenvector by shifted inverse iteration.
+    ///
+    /// `self - sigma * I` is LU-factored once and each step solves with the
+    /// factors. The eigenvalue is the Rayleigh quotient of the final vector.
+    ///
+    /// # Parameters
+    ///
+    /// - `sigma`: Shift near the wanted eigenvalue. It must not be an eigenvalue itself.
+    /// - `tol`: Iteration stops once the residual `|B v - μ v|` of the inverse iteration is at
+    ///   most `tol` times `|μ|`, where `B` is the inverse of the shifted matrix and `μ` its
+    ///   Rayleigh quotient.
+    /// - `max_iter`: Maximum number of solves.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the eigenvalue and a unit eigenvector, or an error if the
+    /// matrix is not square or is empty, the shifted matrix is singular, or one carrying the
+    /// last estimate if the iteration has not converged.
+    pub fn eigenpair_near(
+        &self,
+        sigma: f64,
+        tol: f64,
+        max_iter: usize,
+    ) -> Result<(f64, Vec<f64>), LinalgError> {
+        self.check_eigen_input()?;
+        let shifted = self.clone() + Matrix::identity(self.rows) * -sigma;
+        let factors = shifted.lu_factors();
+        if factors.singular {
+            return Err(LinalgError::Singular);
+        }
+        let solve = |v: &[f64]| {
+            let b = Matrix::from_vec(v.len(), 1, v.to_vec()).unwrap();
+            factors.substitute(&b).into_vec()
+        };
+        match power_method(self.rows, solve, tol, max_iter) {
+            Ok((_, v)) => {
+                let av = self.mul_vec(&v)?;
+                Ok((v.iter().zip(&av).map(|(x, y)| x * y).sum(), v))
+            }
+            Err(mu) => Err(LinalgError::EigenNotConverged {
+                iterations: max_iter,
+                estimate: sigma + 1. / mu,
+            }),
+        }
+    }
+
+    fn check_eigen_input(&self) -> Result<(), LinalgError> {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquare);
+        }
+        if self.rows == 0 {
+            return Err(LinalgError::InvalidArgument(
+                "Eigenvalue iteration needs a non-empty matrix".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+// Power iteration on the linear map `apply`. Returns the Rayleigh quotient
+// `λ` and unit vector `v` once `|apply(v) - λ v| <= tol * |λ|`, or the last
+// quotient if that has not happened within `max_iter` steps. Stopping on the
+// residual rather than on the change in `λ` means a quotient that has settled
+// without `v` being an eigenvector, as happens when the two largest
+// eigenvalues are `±λ`, is not reported as converged.
+//
+// The start vector is fixed but not uniform: a constant vector is orthogonal
+// to many eigenvectors of structured matrices, such as every eigenvector but
+// one of a matrix whose rows all have the same sum.
+fn power_method<F: FnMut(&[f64]) -> Vec<f64>>(
+    n: usize,
+    mut apply: F,
+    tol: f64,
+    max_iter: usize,
+) -> Result<(f64, Vec<f64>), f64> {
+    let start: Vec<f64> = (0..n).map(|i| 1. / (i + 1) as f64).collect();
+    let start_norm = start.iter().map(|x| x * x).sum::<f64>().sqrt();
+    let mut v: Vec<f64> = start.iter().map(|x| x / start_norm).collect();
+    let mut lambda = f64::NAN;
+    for _ in 0..max_iter {
+        let w = apply(&v);
+        lambda = v.iter().zip(&w).map(|(x, y)| x * y).sum();
+        let residual = w
+            .iter()
+            .zip(&v)
+            .map(|(y, x)| (y - lambda * x).powi(2))
+            .sum::<f64>()
+            .sqrt();
+        if residual <= tol * lambda.abs() {
+            return Ok((lambda, v));
+        }
+        let norm = w.iter().map(|x| x * x).sum::<f64>().sqrt();
+        v = w.iter().map(|x| x / norm).collect();
+    }
+    Err(lambda)
+}
+
+fn off_diagonal_norm(a: &Matrix) -> f64 {
+    let mut sum = 0.;
+    for i in 0..a.rows {
+        for j in 0..a.cols {
+            if i != j {
+                sum += a[(i, j)] * a[(i, j)];
+            }
+        }
+    }
+    sum.sqrt()
+}
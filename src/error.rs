@@ -0,0 +1,97 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+
+/// Errors returned by fallible matrix operations.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LinalgError {
+    /// An operand did not have the shape the operation requires.
+    ShapeMismatch {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+    /// The operation is only defined for square matrices.
+    NotSquare,
+    /// The matrix is singular to working precision.
+    Singular,
+    /// An index was not less than the length of the dimension it indexes.
+    IndexOutOfBounds { index: usize, bound: usize },
+    /// A row of nested input data had the wrong number of columns.
+    InconsistentRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// A column of nested input data had the wrong number of rows.
+    InconsistentCol {
+        col: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// A slice or buffer had the wrong number of elements.
+    LengthMismatch { expected: usize, found: usize },
+    /// An argument was invalid for a reason not covered by the other variants.
+    InvalidArgument(String),
+    /// Text input could not be read or parsed as a matrix.
+    Parse(String),
+    /// An iterative method did not reach its tolerance within the allowed iterations.
+    NotConverged { iterations: usize },
+    /// An eigenvalue iteration did not converge. Carries the last eigenvalue estimate.
+    EigenNotConverged { iterations: usize, estimate: f64 },
+}
+
+impl Display for LinalgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LinalgError::ShapeMismatch { expected, found } => write!(
+                f,
+                "Shape mismatch: expected ({}, {}) but found ({}, {})",
+                expected.0, expected.1, found.0, found.1
+            ),
+            LinalgError::NotSquare => write!(f, "Operation is only defined for square matrices"),
+            LinalgError::Singular => write!(f, "Matrix is singular"),
+            LinalgError::IndexOutOfBounds { index, bound } => write!(
+                f,
+                "index out of bounds: the bound is {} but the index is {}",
+                bound, index
+            ),
+            LinalgError::InconsistentRow {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Inconsistent column length in row {}: expected {} columns but found {}",
+                row, expected, found
+            ),
+            LinalgError::InconsistentCol {
+                col,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Inconsistent row length in column {}: expected {} rows but found {}",
+                col, expected, found
+            ),
+            LinalgError::LengthMismatch { expected, found } => write!(
+                f,
+                "Length mismatch: expected {} elements but found {}",
+                expected, found
+            ),
+            LinalgError::InvalidArgument(msg) => write!(f, "{}", msg),
+            LinalgError::Parse(msg) => write!(f, "Parse error: {}", msg),
+            LinalgError::NotConverged { iterations } => {
+                write!(f, "Did not converge within {} iterations", iterations)
+            }
+            LinalgError::EigenNotConverged {
+                iterations,
+                estimate,
+            } => write!(
+                f,
+                "Did not converge within {} iterations; last eigenvalue estimate was {}",
+                iterations, estimate
+            ),
+        }
+    }
+}
+
+impl Error for LinalgError {}
@@ -0,0 +1,164 @@
+use std::mem;
+use std::slice;
+use std::vec;
+
+use crate::Matrix;
+
+impl Matrix {
+    /// Iterates over references to the elements in row-major order.
+    ///
+    /// Element `(i, j)` is yielded at position `i * cols + j`.
+    pub fn iter(&self) -> slice::Iter<'_, f64> {
+        self.data.iter()
+    }
+
+    /// Iterates over mutable references to the elements in row-major order.
+    ///
+    /// Element `(i, j)` is yielded at position `i * cols + j`.
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, f64> {
+        self.data.iter_mut()
+    }
+
+    /// Iterates over the elements in row-major order together with their `(i, j)` indices.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = ((usize, usize), &f64)> {
+        let cols = self.cols;
+        self.data
+            .iter()
+            .scan((0, 0), move |pos, x| Some((advance(pos, cols), x)))
+    }
+
+    /// Iterates mutably over the elements in row-major order together with their `(i, j)`
+    /// indices.
+    pub fn indexed_iter_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut f64)> {
+        let cols = self.cols;
+        self.data
+            .iter_mut()
+            .scan((0, 0), move |pos, x| Some((advance(pos, cols), x)))
+    }
+
+    /// Iterates over the rows of the matrix as slices, top to bottom.
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding `rows` slices of length `cols`.
+    pub fn rows_iter(&self) -> impl Iterator<Item = &[f64]> {
+        self.rows()
+    }
+
+    /// Iterates over the rows of the matrix as slices, in either direction.
+    ///
+    /// # Returns
+    ///
+    /// A double-ended iterator yielding `rows` slices of length `cols`.
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = &[f64]> + DoubleEndedIterator {
+        (0..self.rows).map(move |i| &self.data[i * self.cols..(i + 1) * self.cols])
+    }
+
+    /// Iterates over the rows of the matrix as mutable slices, in either direction.
+    ///
+    /// # Returns
+    ///
+    /// A double-ended iterator yielding `rows` disjoint slices of length `cols`.
+    pub fn rows_mut(&mut self) -> impl ExactSizeIterator<Item = &mut [f64]> + DoubleEndedIterator {
+        RowsMut {
+            rest: &mut self.data,
+            cols: self.cols,
+            remaining: self.rows,
+        }
+    }
+
+    /// Iterates over the columns of the matrix, left to right.
+    ///
+    /// Columns are not contiguous in the row-major buffer, so each one is copied.
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding `cols` vectors of length `rows`.
+    pub fn cols_iter(&self) -> impl Iterator<Item = Vec<f64>> + '_ {
+        self.cols()
+    }
+
+    /// Iterates over copies of the columns of the matrix, in either direction.
+    ///
+    /// # Returns
+    ///
+    /// A double-ended iterator yielding `cols` vectors of length `rows`.
+    pub fn cols(&self) -> impl ExactSizeIterator<Item = Vec<f64>> + DoubleEndedIterator + '_ {
+        (0..self.cols).map(move |j| self.col(j))
+    }
+}
+
+// Returns the current position and steps it to the next row-major index.
+fn advance(pos: &mut (usize, usize), cols: usize) -> (usize, usize) {
+    let current = *pos;
+    pos.1 += 1;
+    if pos.1 == cols {
+        *pos = (pos.0 + 1, 0);
+    }
+    current
+}
+
+// Splits rows off either end of the remaining buffer. Counting rows separately
+// from the buffer keeps zero-width rows working.
+struct RowsMut<'a> {
+    rest: &'a mut [f64],
+    cols: usize,
+    remaining: usize,
+}
+
+impl<'a> Iterator for RowsMut<'a> {
+    type Item = &'a mut [f64];
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let (row, rest) = mem::take(&mut self.rest).split_at_mut(self.cols);
+        self.rest = rest;
+        Some(row)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl DoubleEndedIterator for RowsMut<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let split = self.rest.len() - self.cols;
+        let (rest, row) = mem::take(&mut self.rest).split_at_mut(split);
+        self.rest = rest;
+        Some(row)
+    }
+}
+
+impl ExactSizeIterator for RowsMut<'_> {}
+
+/// Consumes the matrix, yielding its elements by value in row-major order.
+impl IntoIterator for Matrix {
+    type Item = f64;
+    type IntoIter = vec::IntoIter<f64>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Matrix {
+    type Item = &'a f64;
+    type IntoIter = slice::Iter<'a, f64>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Matrix {
+    type Item = &'a mut f64;
+    type IntoIter = slice::IterMut<'a, f64>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
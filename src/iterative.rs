@@ -0,0 +1,148 @@
+use crate::{LinalgError, Matrix};
+
+/// Number of consecutive residual increases after which an iteration is
+/// reported as diverging and stopped.
+const DIVERGENCE_STEPS: usize = 5;
+
+/// Outcome of a stationary iterative solve.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IterativeSolveResult {
+    /// The last iterate, with the same shape as the right-hand side.
+    pub solution: Matrix,
+    /// Number of sweeps performed.
+    pub iterations: usize,
+    /// Relative residual `|b - A x| / |b|` of `solution`, in the Frobenius norm.
+    pub residual: f64,
+    /// Whether `residual` reached the requested tolerance.
+    pub converged: bool,
+    /// Whether the iteration was stopped early because the residual kept growing.
+    pub diverging: bool,
+}
+
+impl Matrix {
+    /// Solves `self * x = b` with the Jacobi iteration, starting from `x = 0`.
+    ///
+    /// Every sweep computes each component from the previous iterate. The
+    /// iteration converges for strictly diagonally dominant matrices.
+    ///
+    /// # Parameters
+    ///
+    /// - `b`: Right-hand side with as many rows as `self`.
+    /// - `tol`: Relative residual at which to stop.
+    /// - `max_iter`: Maximum number of sweeps.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the outcome of the iteration, converged or not, or an error
+    /// if `self` is not square, the row counts differ or a diagonal element is zero.
+    pub fn solve_jacobi(
+        &self,
+        b: &Matrix,
+        tol: f64,
+        max_iter: usize,
+    ) -> Result<IterativeSolveResult, LinalgError> {
+        self.iterate(b, tol, max_iter, |a, b, x| {
+            let prev = x.clone();
+            for col in 0..b.cols {
+                for i in 0..a.rows {
+                    let off: f64 = (0..a.cols)
+                        .filter(|&j| j != i)
+                        .map(|j| a[(i, j)] * prev[(j, col)])
+                        .sum();
+                    x[(i, col)] = (b[(i, col)] - off) / a[(i, i)];
+                }
+            }
+        })
+    }
+
+    /// Solves `self * x = b` with the Gauss-Seidel iteration, starting from `x = 0`.
+    ///
+    /// Unlike Jacobi, each component is updated in place and used immediately
+    /// by the rest of the sweep, which usually roughly halves the iteration
+    /// count.
+    ///
+    /// # Parameters
+    ///
+    /// - `b`: Right-hand side with as many rows as `self`.
+    /// - `tol`: Relative residual at which to stop.
+    /// - `max_iter`: Maximum number of sweeps.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the outcome of the iteration, converged or not, or an error
+    /// if `self` is not square, the row counts differ or a diagonal element is zero.
+    pub fn solve_gauss_seidel(
+        &self,
+        b: &Matrix,
+        tol: f64,
+        max_iter: usize,
+    ) -> Result<IterativeSolveResult, LinalgError> {
+        self.iterate(b, tol, max_iter, |a, b, x| {
+            for col in 0..b.cols {
+                for i in 0..a.rows {
+                    let off: f64 = (0..a.cols)
+                        .filter(|&j| j != i)
+                        .map(|j| a[(i, j)] * x[(j, col)])
+                        .sum();
+                    x[(i, col)] = (b[(i, col)] - off) / a[(i, i)];
+                }
+            }
+        })
+    }
+
+    // Runs `sweep` until the relative residual drops to `tol`, `max_iter` sweeps
+    // have been done, or the residual has grown `DIVERGENCE_STEPS` times in a row.
+    fn iterate<F: Fn(&Matrix, &Matrix, &mut Matrix)>(
+        &self,
+        b: &Matrix,
+        tol: f64,
+        max_iter: usize,
+        sweep: F,
+    ) -> Result<IterativeSolveResult, LinalgError> {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquare);
+        }
+        if b.rows != self.rows {
+            return Err(LinalgError::ShapeMismatch {
+                expected: (self.rows, b.cols),
+                found: b.shape(),
+            });
+        }
+        if let Some(i) = (0..self.rows).find(|&i| self[(i, i)] == 0.) {
+            return Err(LinalgError::InvalidArgument(format!(
+                "Diagonal element ({}, {}) is zero",
+                i, i
+            )));
+        }
+
+        let b_norm = b.frobenius_norm();
+        let residual_of = |x: &Matrix| {
+            let r = (self * x).zip_map(b, |ax, bx| bx - ax).unwrap();
+            if b_norm == 0. {
+                r.frobenius_norm()
+            } else {
+                r.frobenius_norm() / b_norm
+            }
+        };
+
+        let mut x = Matrix::zeros(self.rows, b.cols);
+        let mut residual = residual_of(&x);
+        let mut iterations = 0;
+        let mut growth = 0;
+        while residual > tol && iterations < max_iter && growth < DIVERGENCE_STEPS {
+            sweep(self, b, &mut x);
+            iterations += 1;
+            let next = residual_of(&x);
+            growth = if next > residual { growth + 1 } else { 0 };
+            residual = next;
+        }
+
+        Ok(IterativeSolveResult {
+            solution: x,
+            iterations,
+            residual,
+            converged: residual <= tol,
+            diverging: growth >= DIVERGENCE_STEPS,
+        })
+    }
+}
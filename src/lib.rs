@@ -1,8 +1,33 @@
 #![crate_name = "linalg"]
 
-use std::cmp::{max, PartialEq};
+mod banded;
+mod block;
+mod csv;
+mod decomposition;
+mod eigen;
+mod error;
+mod iter;
+mod iterative;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "serde")]
+mod serialize;
+mod vector;
+mod view;
+
+pub use banded::{BandedLu, BandedMatrix};
+pub use decomposition::{Lu, RefinedSolveResult, Svd};
+pub use error::LinalgError;
+pub use iterative::IterativeSolveResult;
+pub use vector::Vector;
+pub use view::{MatrixView, MatrixViewMut};
+
+use std::cmp::PartialEq;
 use std::fmt::{self, Display};
-use std::ops::{Add, Index, IndexMut, Mul, MulAssign};
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg};
 
 #[derive(Debug)]
 /// A basic matrix representation
@@ -12,105 +37,1467 @@ pub struct Matrix {
     data: Vec<f64>,
 }
 
-impl Matrix {
-    /// Creates a new matrix filled with a scalar value.
+/// Matrix norms supported by [`Matrix::norm`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Norm {
+    /// Maximum absolute column sum.
+    One,
+    /// Maximum absolute row sum.
+    Inf,
+    /// Square root of the sum of the squares of every element.
+    Frobenius,
+}
+
+impl Matrix {
+    /// Creates a new matrix filled with a scalar value.
+    ///
+    /// # Parameters
+    ///
+    /// - `n_rows`: Number of rows in the matrix.
+    /// - `n_cols`: Number of columns in the matrix.
+    /// - `val`: Scalar value to fill the matrix.
+    ///
+    /// # Returns
+    ///
+    /// A new `Matrix` with dimensions `n_rows` x `n_cols` filled with `val`.
+    ///
+    /// Either dimension may be zero, in which case the matrix holds no
+    /// elements but still reports the requested shape (e.g. 1x0 or 0x0).
+    pub fn from_scalar(n_rows: usize, n_cols: usize, val: f64) -> Self {
+        Matrix {
+            rows: n_rows,
+            cols: n_cols,
+            data: vec![val; n_cols * n_rows],
+        }
+    }
+
+    /// Creates a new matrix filled with zeros.
+    ///
+    /// # Parameters
+    ///
+    /// - `n_rows`: Number of rows in the matrix.
+    /// - `n_cols`: Number of columns in the matrix.
+    ///
+    /// # Returns
+    ///
+    /// A new `Matrix` with dimensions `n_rows` x `n_cols` filled with `0.0`.
+    pub fn zeros(n_rows: usize, n_cols: usize) -> Self {
+        Self::from_scalar(n_rows, n_cols, 0.)
+    }
+
+    /// Creates a new matrix filled with ones.
+    ///
+    /// # Parameters
+    ///
+    /// - `n_rows`: Number of rows in the matrix.
+    /// - `n_cols`: Number of columns in the matrix.
+    ///
+    /// # Returns
+    ///
+    /// A new `Matrix` with dimensions `n_rows` x `n_cols` filled with `1.0`.
+    pub fn ones(n_rows: usize, n_cols: usize) -> Self {
+        Self::from_scalar(n_rows, n_cols, 1.)
+    }
+
+    /// Creates a matrix of zeros with the same shape as `other`.
+    ///
+    /// # Returns
+    ///
+    /// A new `Matrix` with the shape of `other` filled with `0.0`.
+    pub fn zeros_like(other: &Matrix) -> Self {
+        Self::zeros(other.rows, other.cols)
+    }
+
+    /// Creates a matrix of ones with the same shape as `other`.
+    ///
+    /// # Returns
+    ///
+    /// A new `Matrix` with the shape of `other` filled with `1.0`.
+    pub fn ones_like(other: &Matrix) -> Self {
+        Self::ones(other.rows, other.cols)
+    }
+
+    /// Creates a new matrix whose elements are computed from their indices.
+    ///
+    /// # Parameters
+    ///
+    /// - `n_rows`: Number of rows in the matrix.
+    /// - `n_cols`: Number of columns in the matrix.
+    /// - `f`: Closure called as `f(i, j)` for every element, in row-major order.
+    ///
+    /// # Returns
+    ///
+    /// A new `Matrix` with dimensions `n_rows` x `n_cols` where element `(i, j)` is `f(i, j)`.
+    pub fn from_fn<F: FnMut(usize, usize) -> f64>(n_rows: usize, n_cols: usize, mut f: F) -> Self {
+        let mut data = Vec::with_capacity(n_rows * n_cols);
+        for i in 0..n_rows {
+            for j in 0..n_cols {
+                data.push(f(i, j));
+            }
+        }
+        Matrix {
+            rows: n_rows,
+            cols: n_cols,
+            data,
+        }
+    }
+
+    /// Creates a new matrix from a 2D vector of floating-point numbers.
+    ///
+    /// # Parameters
+    ///
+    /// - `n_rows`: Number of rows in the matrix.
+    /// - `n_cols`: Number of columns in the matrix.
+    /// - `data`: 2D vector containing matrix elements.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the created `Matrix` or an error if dimensions are inconsistent.
+    /// A row of the wrong length is reported with its index. A wrong number of rows is
+    /// reported as a shape mismatch whose found column count is the length of the first row.
+    pub fn from_2d_vec(
+        n_rows: usize,
+        n_cols: usize,
+        data: Vec<Vec<f64>>,
+    ) -> Result<Self, LinalgError> {
+        let mut data_formatted = Vec::<f64>::with_capacity(n_rows * n_cols);
+        if data.len() != n_rows {
+            return Err(LinalgError::ShapeMismatch {
+                expected: (n_rows, n_cols),
+                found: (data.len(), data.first().map_or(0, Vec::len)),
+            });
+        }
+
+        for (i, row) in data.into_iter().enumerate() {
+            if row.len() != n_cols {
+                return Err(LinalgError::InconsistentRow {
+                    row: i,
+                    expected: n_cols,
+                    found: row.len(),
+                });
+            }
+            data_formatted.extend(row);
+        }
+
+        Ok(Matrix {
+            rows: n_rows,
+            cols: n_cols,
+            data: data_formatted,
+        })
+    }
+
+    /// Creates a new matrix from a flat vector of elements in row-major order.
+    ///
+    /// The vector is moved into the matrix without copying.
+    ///
+    /// # Parameters
+    ///
+    /// - `n_rows`: Number of rows in the matrix.
+    /// - `n_cols`: Number of columns in the matrix.
+    /// - `data`: Matrix elements laid out row by row.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the created `Matrix` or an error if `data.len()` is not `n_rows * n_cols`.
+    pub fn from_vec(n_rows: usize, n_cols: usize, data: Vec<f64>) -> Result<Self, LinalgError> {
+        if data.len() != n_rows * n_cols {
+            return Err(LinalgError::LengthMismatch {
+                expected: n_rows * n_cols,
+                found: data.len(),
+            });
+        }
+
+        Ok(Matrix {
+            rows: n_rows,
+            cols: n_cols,
+            data,
+        })
+    }
+
+    /// Creates a new matrix by collecting elements in row-major order from an iterator.
+    ///
+    /// Exactly `n_rows * n_cols` elements are taken. Any further elements are left
+    /// unconsumed, so unbounded iterators are fine.
+    ///
+    /// # Parameters
+    ///
+    /// - `n_rows`: Number of rows in the matrix.
+    /// - `n_cols`: Number of columns in the matrix.
+    /// - `iter`: Source of the matrix elements, row by row.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the created `Matrix` or an error if `iter` yields fewer than
+    /// `n_rows * n_cols` elements.
+    pub fn from_iter<I: IntoIterator<Item = f64>>(
+        n_rows: usize,
+        n_cols: usize,
+        iter: I,
+    ) -> Result<Self, LinalgError> {
+        let data: Vec<f64> = iter.into_iter().take(n_rows * n_cols).collect();
+        Self::from_vec(n_rows, n_cols, data)
+    }
+
+    /// Creates a new matrix from a slice of rows, inferring the shape.
+    ///
+    /// # Parameters
+    ///
+    /// - `rows`: Rows of the matrix, each of the same length.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the created `Matrix` or an error if the rows differ in length.
+    /// An empty slice produces a 0x0 matrix.
+    pub fn from_rows(rows: &[&[f64]]) -> Result<Self, LinalgError> {
+        let n_cols = rows.first().map_or(0, |row| row.len());
+        let mut data = Vec::with_capacity(rows.len() * n_cols);
+        for (i, row) in rows.iter().enumerate() {
+            if row.len() != n_cols {
+                return Err(LinalgError::InconsistentRow {
+                    row: i,
+                    expected: n_cols,
+                    found: row.len(),
+                });
+            }
+            data.extend_from_slice(row);
+        }
+
+        Ok(Matrix {
+            rows: rows.len(),
+            cols: n_cols,
+            data,
+        })
+    }
+
+    /// Creates a new matrix from a slice of columns, inferring the shape.
+    ///
+    /// # Parameters
+    ///
+    /// - `cols`: Columns of the matrix, each of the same length.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the created `Matrix` or an error if the columns differ in length.
+    /// An empty slice produces a 0x0 matrix.
+    pub fn from_cols(cols: &[&[f64]]) -> Result<Self, LinalgError> {
+        let n_rows = cols.first().map_or(0, |col| col.len());
+        if let Some((j, col)) = cols.iter().enumerate().find(|(_, col)| col.len() != n_rows) {
+            return Err(LinalgError::InconsistentCol {
+                col: j,
+                expected: n_rows,
+                found: col.len(),
+            });
+        }
+
+        Ok(Self::from_fn(n_rows, cols.len(), |i, j| cols[j][i]))
+    }
+
+    /// Assembles a matrix from a grid of blocks.
+    ///
+    /// All blocks in a block-row must have the same number of rows and all
+    /// blocks in a block-column the same number of columns.
+    ///
+    /// # Parameters
+    ///
+    /// - `blocks`: Grid of sub-matrices, one inner `Vec` per block-row.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the assembled matrix or an error naming the first block
+    /// whose shape does not fit the grid.
+    pub fn from_blocks(blocks: Vec<Vec<Matrix>>) -> Result<Self, LinalgError> {
+        let block_cols = blocks.first().map_or(0, |row| row.len());
+        let widths: Vec<usize> = blocks
+            .first()
+            .map_or(vec![], |row| row.iter().map(|b| b.cols).collect());
+        let mut heights = Vec::with_capacity(blocks.len());
+
+        for (bi, block_row) in blocks.iter().enumerate() {
+            if block_row.len() != block_cols {
+                return Err(LinalgError::InvalidArgument(format!(
+                    "Block row {} has {} blocks but block row 0 has {}",
+                    bi,
+                    block_row.len(),
+                    block_cols
+                )));
+            }
+            let height = block_row.first().map_or(0, |b| b.rows);
+            for (bj, block) in block_row.iter().enumerate() {
+                if block.rows != height {
+                    return Err(LinalgError::InvalidArgument(format!(
+                        "Block ({}, {}) has {} rows but block ({}, 0) has {}",
+                        bi, bj, block.rows, bi, height
+                    )));
+                }
+                if block.cols != widths[bj] {
+                    return Err(LinalgError::InvalidArgument(format!(
+                        "Block ({}, {}) has {} cols but block (0, {}) has {}",
+                        bi, bj, block.cols, bj, widths[bj]
+                    )));
+                }
+            }
+            heights.push(height);
+        }
+
+        let n_rows = heights.iter().sum();
+        let n_cols = widths.iter().sum();
+        let mut data = Vec::with_capacity(n_rows * n_cols);
+        for block_row in &blocks {
+            for i in 0..block_row.first().map_or(0, |b| b.rows) {
+                for block in block_row {
+                    data.extend_from_slice(&block.data[i * block.cols..(i + 1) * block.cols]);
+                }
+            }
+        }
+
+        Ok(Matrix {
+            rows: n_rows,
+            cols: n_cols,
+            data,
+        })
+    }
+
+    /// Creates a block-diagonal matrix from a slice of blocks.
+    ///
+    /// Blocks may be rectangular and of different sizes; every element off
+    /// the diagonal blocks is zero.
+    ///
+    /// # Parameters
+    ///
+    /// - `blocks`: Matrices to place along the diagonal, top-left to bottom-right.
+    ///
+    /// # Returns
+    ///
+    /// A matrix whose row and column counts are the sums of those of `blocks`. An empty slice gives a 0x0 matrix.
+    pub fn block_diag(blocks: &[Matrix]) -> Self {
+        let n_rows = blocks.iter().map(|b| b.rows).sum();
+        let n_cols = blocks.iter().map(|b| b.cols).sum();
+        let mut out = Self::zeros(n_rows, n_cols);
+        let (mut row_offset, mut col_offset) = (0, 0);
+        for block in blocks {
+            for i in 0..block.rows {
+                let start = (row_offset + i) * n_cols + col_offset;
+                out.data[start..start + block.cols]
+                    .copy_from_slice(&block.data[i * block.cols..(i + 1) * block.cols]);
+            }
+            row_offset += block.rows;
+            col_offset += block.cols;
+        }
+        out
+    }
+
+    /// Consumes the matrix and returns its elements in row-major order.
+    ///
+    /// # Returns
+    ///
+    /// The underlying `Vec<f64>` of length `rows * cols`.
+    pub fn into_vec(self) -> Vec<f64> {
+        self.data
+    }
+
+    /// Creates an identity matrix of a given size.
+    ///
+    /// # Parameters
+    ///
+    /// - `size`: Size of the square identity matrix (size x size).
+    ///
+    /// # Returns
+    ///
+    /// An identity matrix of dimensions `size` x `size`.
+    pub fn identity(size: usize) -> Self {
+        let mut data = vec![0.0; size * size];
+        for i in 0..size {
+            data[i * size + i] = 1.0;
+        }
+        Matrix {
+            rows: size,
+            cols: size,
+            data,
+        }
+    }
+
+    /// Creates a square diagonal matrix from a slice of values.
+    ///
+    /// # Parameters
+    ///
+    /// - `diag`: Values to place on the main diagonal.
+    ///
+    /// # Returns
+    ///
+    /// A `diag.len()` x `diag.len()` matrix with `diag` on the main diagonal and zeros elsewhere.
+    pub fn from_diag(diag: &[f64]) -> Self {
+        Self::from_diag_offset(diag, 0)
+    }
+
+    /// Creates a square diagonal matrix from a slice of values.
+    ///
+    /// The inverse of [`Matrix::diagonal`] for square matrices; equivalent to [`Matrix::from_diag`].
+    pub fn from_diagonal(values: &[f64]) -> Self {
+        Self::from_diag(values)
+    }
+
+    /// Creates a square matrix with the given values on the `k`-th diagonal.
+    ///
+    /// Positive `k` selects a diagonal above the main diagonal and negative `k`
+    /// one below it, as in numpy's `np.diag(v, k)`.
+    ///
+    /// # Parameters
+    ///
+    /// - `diag`: Values to place on the diagonal.
+    /// - `k`: Offset of the diagonal from the main diagonal.
+    ///
+    /// # Returns
+    ///
+    /// A square matrix of size `diag.len() + |k|` with `diag` on the `k`-th diagonal and zeros elsewhere.
+    pub fn from_diag_offset(diag: &[f64], k: i64) -> Self {
+        let offset = k.unsigned_abs() as usize;
+        let size = diag.len() + offset;
+        let mut out = Self::zeros(size, size);
+        for (n, &val) in diag.iter().enumerate() {
+            if k >= 0 {
+                out[(n, n + offset)] = val;
+            } else {
+                out[(n + offset, n)] = val;
+            }
+        }
+        out
+    }
+
+    /// Creates a square tridiagonal matrix with constant bands.
+    ///
+    /// # Parameters
+    ///
+    /// - `n`: Size of the square matrix.
+    /// - `sub`: Value on the diagonal below the main diagonal.
+    /// - `diag`: Value on the main diagonal.
+    /// - `sup`: Value on the diagonal above the main diagonal.
+    ///
+    /// # Returns
+    ///
+    /// An `n` x `n` matrix with the given bands and zeros elsewhere.
+    pub fn tridiagonal(n: usize, sub: f64, diag: f64, sup: f64) -> Self {
+        Self::from_fn(n, n, |i, j| {
+            if i == j + 1 {
+                sub
+            } else if i == j {
+                diag
+            } else if j == i + 1 {
+                sup
+            } else {
+                0.
+            }
+        })
+    }
+
+    /// Creates a square tridiagonal matrix from its three bands.
+    ///
+    /// # Parameters
+    ///
+    /// - `sub`: Values below the main diagonal, of length `n - 1`.
+    /// - `diag`: Values on the main diagonal, of length `n`.
+    /// - `sup`: Values above the main diagonal, of length `n - 1`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the `n` x `n` matrix or an error if the band lengths are inconsistent.
+    pub fn from_tridiagonal(sub: &[f64], diag: &[f64], sup: &[f64]) -> Result<Self, LinalgError> {
+        let off_len = diag.len().saturating_sub(1);
+        for band in [sub, sup] {
+            if band.len() != off_len {
+                return Err(LinalgError::LengthMismatch {
+                    expected: off_len,
+                    found: band.len(),
+                });
+            }
+        }
+
+        let mut out = Self::from_diag(diag);
+        for k in 0..off_len {
+            out[(k + 1, k)] = sub[k];
+            out[(k, k + 1)] = sup[k];
+        }
+        Ok(out)
+    }
+
+    /// Creates a Toeplitz matrix from its first column and first row.
+    ///
+    /// Element `(i, j)` is `first_col[i - j]` when `i >= j` and `first_row[j - i]` otherwise.
+    ///
+    /// # Parameters
+    ///
+    /// - `first_col`: First column of the matrix, which sets the number of rows.
+    /// - `first_row`: First row of the matrix, which sets the number of columns.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the matrix or an error if `first_col[0] != first_row[0]`.
+    pub fn toeplitz(first_col: &[f64], first_row: &[f64]) -> Result<Self, LinalgError> {
+        if let (Some(c), Some(r)) = (first_col.first(), first_row.first()) {
+            if c != r {
+                return Err(LinalgError::InvalidArgument(format!(
+                    "First column and first row must share their first element. Column: {}, Row: {}",
+                    c, r
+                )));
+            }
+        }
+        Ok(Self::from_fn(first_col.len(), first_row.len(), |i, j| {
+            if i >= j {
+                first_col[i - j]
+            } else {
+                first_row[j - i]
+            }
+        }))
+    }
+
+    /// Creates a circulant matrix whose first row is `c`.
+    ///
+    /// Each subsequent row is the previous row cyclically shifted one place to the right.
+    ///
+    /// # Parameters
+    ///
+    /// - `c`: First row of the matrix.
+    ///
+    /// # Returns
+    ///
+    /// A `c.len()` x `c.len()` circulant matrix.
+    pub fn circulant(c: &[f64]) -> Self {
+        let n = c.len();
+        Self::from_fn(n, n, |i, j| c[(j + n - i) % n])
+    }
+
+    /// Creates a Vandermonde matrix with increasing powers.
+    ///
+    /// Element `(i, j)` is `x[i]^j`, as with numpy's `vander(x, increasing=True)`.
+    ///
+    /// # Parameters
+    ///
+    /// - `x`: Sample points, one per row.
+    /// - `degree`: Highest power, giving `degree + 1` columns.
+    ///
+    /// # Returns
+    ///
+    /// An `x.len()` x `(degree + 1)` matrix.
+    pub fn vandermonde(x: &[f64], degree: usize) -> Self {
+        Self::from_fn(x.len(), degree + 1, |i, j| x[i].powi(j as i32))
+    }
+
+    /// Creates a Vandermonde matrix with decreasing powers.
+    ///
+    /// Element `(i, j)` is `x[i]^(degree - j)`, matching MATLAB's `vander`.
+    ///
+    /// # Parameters
+    ///
+    /// - `x`: Sample points, one per row.
+    /// - `degree`: Highest power, giving `degree + 1` columns.
+    ///
+    /// # Returns
+    ///
+    /// An `x.len()` x `(degree + 1)` matrix.
+    pub fn vandermonde_decreasing(x: &[f64], degree: usize) -> Self {
+        Self::from_fn(x.len(), degree + 1, |i, j| x[i].powi((degree - j) as i32))
+    }
+
+    /// Creates the `n` x `n` Hilbert matrix, with elements `1 / (i + j + 1)`.
+    ///
+    /// Hilbert matrices are notoriously ill-conditioned, which makes them
+    /// useful for testing solvers.
+    pub fn hilbert(n: usize) -> Self {
+        Self::from_fn(n, n, |i, j| 1. / (i + j + 1) as f64)
+    }
+
+    /// Creates the `n` x `n` symmetric Pascal matrix of binomial coefficients.
+    ///
+    /// Element `(i, j)` is `C(i + j, i)`. The elements are built with the
+    /// recurrence `P(i, j) = P(i - 1, j) + P(i, j - 1)`, so no factorials are formed.
+    pub fn pascal(n: usize) -> Self {
+        let mut out = Self::ones(n, n);
+        for i in 1..n {
+            for j in 1..n {
+                out[(i, j)] = out[(i - 1, j)] + out[(i, j - 1)];
+            }
+        }
+        out
+    }
+
+    /// Creates the permutation matrix for `perm`.
+    ///
+    /// Row `k` of the result is row `perm[k]` of the identity, so
+    /// `permutation(perm) * m == m.permute_rows(perm)`.
+    ///
+    /// # Parameters
+    ///
+    /// - `perm`: A permutation of `0..perm.len()`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the `perm.len()` x `perm.len()` matrix or an error naming the
+    /// first repeated or out-of-range index.
+    pub fn permutation(perm: &[usize]) -> Result<Self, LinalgError> {
+        validate_permutation(perm, perm.len())?;
+        let n = perm.len();
+        let mut out = Self::zeros(n, n);
+        for (k, &p) in perm.iter().enumerate() {
+            out[(k, p)] = 1.;
+        }
+        Ok(out)
+    }
+
+    /// Creates a possibly rectangular matrix with ones on the `k`-th diagonal.
+    ///
+    /// Positive `k` selects a diagonal above the main diagonal and negative `k`
+    /// one below it, as in numpy's `eye`. `eye(n, n, 0)` equals `identity(n)`.
+    ///
+    /// # Parameters
+    ///
+    /// - `n_rows`: Number of rows in the matrix.
+    /// - `n_cols`: Number of columns in the matrix.
+    /// - `k`: Offset of the diagonal from the main diagonal.
+    ///
+    /// # Returns
+    ///
+    /// A new `Matrix` with dimensions `n_rows` x `n_cols` with `1.0` where `j - i == k` and `0.0` elsewhere.
+    pub fn eye(n_rows: usize, n_cols: usize, k: i64) -> Self {
+        Self::from_fn(n_rows, n_cols, |i, j| {
+            if j as i64 - i as i64 == k {
+                1.
+            } else {
+                0.
+            }
+        })
+    }
+
+    /// Creates a row vector of `num` evenly spaced values over `[start, stop]`.
+    ///
+    /// # Parameters
+    ///
+    /// - `start`: First value of the sequence.
+    /// - `stop`: Last value of the sequence.
+    /// - `num`: Number of values to generate.
+    ///
+    /// # Returns
+    ///
+    /// A 1 x `num` matrix. Both endpoints are included exactly when `num >= 2`.
+    pub fn linspace(start: f64, stop: f64, num: usize) -> Self {
+        let data = linspace_values(start, stop, num);
+        Matrix {
+            rows: 1,
+            cols: data.len(),
+            data,
+        }
+    }
+
+    /// Creates a column vector of `num` evenly spaced values over `[start, stop]`.
+    ///
+    /// # Returns
+    ///
+    /// A `num` x 1 matrix with the same values as [`Matrix::linspace`].
+    pub fn linspace_col(start: f64, stop: f64, num: usize) -> Self {
+        let data = linspace_values(start, stop, num);
+        Matrix {
+            rows: data.len(),
+            cols: 1,
+            data,
+        }
+    }
+
+    /// Creates a row vector of values in `[start, stop)` separated by `step`.
+    ///
+    /// Each value is computed as `start + i * step` so rounding errors do not
+    /// accumulate along the sequence.
+    ///
+    /// # Parameters
+    ///
+    /// - `start`: First value of the sequence.
+    /// - `stop`: Exclusive end of the sequence.
+    /// - `step`: Spacing between values.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either a 1 x n matrix or an error if `step` is zero or points away from `stop`.
+    pub fn arange(start: f64, stop: f64, step: f64) -> Result<Self, LinalgError> {
+        let data = arange_values(start, stop, step)?;
+        Ok(Matrix {
+            rows: 1,
+            cols: data.len(),
+            data,
+        })
+    }
+
+    /// Creates a column vector of values in `[start, stop)` separated by `step`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either an n x 1 matrix with the same values as [`Matrix::arange`] or an error.
+    pub fn arange_col(start: f64, stop: f64, step: f64) -> Result<Self, LinalgError> {
+        let data = arange_values(start, stop, step)?;
+        Ok(Matrix {
+            rows: data.len(),
+            cols: 1,
+            data,
+        })
+    }
+
+    /// Returns the shape of the matrix.
+    ///
+    /// # Returns
+    ///
+    /// A tuple representing the matrix shape: (rows, cols)
+    pub fn shape(&self) -> (usize, usize) {
+        // Return the shape of the matrix in the form (rows, cols)
+        (self.rows, self.cols)
+    }
+
+    /// Returns a reference to an element, or `None` if the index is out of bounds.
+    pub fn get(&self, i: usize, j: usize) -> Option<&f64> {
+        self.linear_index(i, j).map(|idx| &self.data[idx])
+    }
+
+    /// Returns a mutable reference to an element, or `None` if the index is out of bounds.
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut f64> {
+        self.linear_index(i, j).map(move |idx| &mut self.data[idx])
+    }
+
+    /// Sets an element, returning an error instead of panicking if the index is out of bounds.
+    ///
+    /// # Returns
+    ///
+    /// A Result that is an `IndexOutOfBounds` error naming the offending row or column index.
+    pub fn set(&mut self, i: usize, j: usize, val: f64) -> Result<(), LinalgError> {
+        match self.linear_index(i, j) {
+            Some(idx) => {
+                self.data[idx] = val;
+                Ok(())
+            }
+            None if i >= self.rows => Err(LinalgError::IndexOutOfBounds {
+                index: i,
+                bound: self.rows,
+            }),
+            None => Err(LinalgError::IndexOutOfBounds {
+                index: j,
+                bound: self.cols,
+            }),
+        }
+    }
+
+    /// Returns an element without checking that the index is in bounds.
+    ///
+    /// # Safety
+    ///
+    /// Calling this with `i >= rows` or `j >= cols` is undefined behavior, even if the resulting
+    /// offset happens to land inside the buffer.
+    pub unsafe fn get_unchecked(&self, i: usize, j: usize) -> f64 {
+        *self.data.get_unchecked(i * self.cols + j)
+    }
+
+    /// Returns a mutable reference to an element without checking that the index is in bounds.
+    ///
+    /// # Safety
+    ///
+    /// Calling this with `i >= rows` or `j >= cols` is undefined behavior, even if the resulting
+    /// offset happens to land inside the buffer.
+    pub unsafe fn get_unchecked_mut(&mut self, i: usize, j: usize) -> &mut f64 {
+        self.data.get_unchecked_mut(i * self.cols + j)
+    }
+
+    // Position of element `(i, j)` in the row-major buffer, if it is in bounds.
+    fn linear_index(&self, i: usize, j: usize) -> Option<usize> {
+        if i < self.rows && j < self.cols {
+            Some(i * self.cols + j)
+        } else {
+            None
+        }
+    }
+
+    /// Returns row `i` as a slice into the matrix storage.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= rows`.
+    pub fn row(&self, i: usize) -> &[f64] {
+        if let Err(e) = check_index(i, self.rows) {
+            panic!("{}", e);
+        }
+        &self.data[i * self.cols..(i + 1) * self.cols]
+    }
+
+    /// Returns row `i` as a mutable slice into the matrix storage.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= rows`.
+    pub fn row_mut(&mut self, i: usize) -> &mut [f64] {
+        if let Err(e) = check_index(i, self.rows) {
+            panic!("{}", e);
+        }
+        &mut self.data[i * self.cols..(i + 1) * self.cols]
+    }
+
+    /// Returns a copy of column `j`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `j >= cols`.
+    pub fn col(&self, j: usize) -> Vec<f64> {
+        if let Err(e) = check_index(j, self.cols) {
+            panic!("{}", e);
+        }
+        (0..self.rows).map(|i| self[(i, j)]).collect()
+    }
+
+    /// Overwrites row `i` with `values`.
+    ///
+    /// # Returns
+    ///
+    /// A Result that is an error if `i >= rows` or `values.len() != cols`.
+    pub fn set_row(&mut self, i: usize, values: &[f64]) -> Result<(), LinalgError> {
+        check_index(i, self.rows)?;
+        if values.len() != self.cols {
+            return Err(LinalgError::LengthMismatch {
+                expected: self.cols,
+                found: values.len(),
+            });
+        }
+        self.row_mut(i).copy_from_slice(values);
+        Ok(())
+    }
+
+    /// Overwrites column `j` with `values`.
+    ///
+    /// # Returns
+    ///
+    /// A Result that is an error if `j >= cols` or `values.len() != rows`.
+    pub fn set_col(&mut self, j: usize, values: &[f64]) -> Result<(), LinalgError> {
+        check_index(j, self.cols)?;
+        if values.len() != self.rows {
+            return Err(LinalgError::LengthMismatch {
+                expected: self.rows,
+                found: values.len(),
+            });
+        }
+        for (i, &val) in values.iter().enumerate() {
+            self[(i, j)] = val;
+        }
+        Ok(())
+    }
+
+    /// Transposes the matrix.
+    ///
+    /// # Returns
+    ///
+    /// A new `Matrix` which is the transpose of the current matrix.
+    pub fn transpose(self) -> Self {
+        let mut ret = Matrix {
+            rows: self.cols,
+            cols: self.rows,
+            data: vec![0.; self.cols * self.rows],
+        };
+        for i in 0..ret.rows {
+            for j in 0..ret.cols {
+                // SAFETY: `i < ret.rows == self.cols` and `j < ret.cols == self.rows`.
+                unsafe {
+                    *ret.get_unchecked_mut(i, j) = self.get_unchecked(j, i);
+                }
+            }
+        }
+        ret
+    }
+
+    /// Returns the main diagonal of the matrix.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<f64>` of the elements `(i, i)` for `i` up to `min(rows, cols)`.
+    pub fn diagonal(&self) -> Vec<f64> {
+        (0..self.rows.min(self.cols))
+            .map(|i| self[(i, i)])
+            .collect()
+    }
+
+    /// Returns the `k`-th diagonal of the matrix.
+    ///
+    /// # Parameters
+    ///
+    /// - `k`: Diagonal offset. `0` is the main diagonal, positive values are above it and
+    ///   negative values below it.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<f64>` of the elements `(i, i + k)` that lie inside the matrix, in order of
+    /// increasing row. Offsets past the edge give an empty vector.
+    pub fn diagonal_offset(&self, k: i64) -> Vec<f64> {
+        let (row_start, col_start) = if k >= 0 {
+            (0, k as usize)
+        } else {
+            (k.unsigned_abs() as usize, 0)
+        };
+        (0..)
+            .map(|d| (row_start + d, col_start + d))
+            .take_while(|&(i, j)| i < self.rows && j < self.cols)
+            .map(|(i, j)| self[(i, j)])
+            .collect()
+    }
+
+    /// Sets every element to `val`.
+    pub fn fill(&mut self, val: f64) {
+        self.data.fill(val);
+    }
+
+    /// Recomputes every element in place as `f(i, j)`.
+    ///
+    /// Elements are visited in row-major order, so `f` may carry state between calls.
+    pub fn fill_with<F: FnMut(usize, usize) -> f64>(&mut self, mut f: F) {
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                self.data[i * self.cols + j] = f(i, j);
+            }
+        }
+    }
+
+    /// Sets the `min(rows, cols)` elements `(i, i)` to `val`, leaving everything else unchanged.
+    pub fn fill_diagonal(&mut self, val: f64) {
+        for i in 0..self.rows.min(self.cols) {
+            self[(i, i)] = val;
+        }
+    }
+
+    /// Overwrites the main diagonal with `values`.
+    ///
+    /// # Returns
+    ///
+    /// A Result that is an error if `values.len() != min(rows, cols)`.
+    pub fn set_diagonal(&mut self, values: &[f64]) -> Result<(), LinalgError> {
+        let n = self.rows.min(self.cols);
+        if values.len() != n {
+            return Err(LinalgError::LengthMismatch {
+                expected: n,
+                found: values.len(),
+            });
+        }
+        for (i, &val) in values.iter().enumerate() {
+            self[(i, i)] = val;
+        }
+        Ok(())
+    }
+
+    /// Returns a copy with every element above the `k`-th diagonal set to zero.
+    ///
+    /// Element `(i, j)` is kept when `j <= i + k`, as in numpy's `tril`: `k = 0` is the main
+    /// diagonal, positive `k` lies above it and negative `k` below.
+    pub fn tril(&self, k: i64) -> Matrix {
+        let mut out = self.clone();
+        out.tril_mut(k);
+        out
+    }
+
+    /// Returns a copy with every element below the `k`-th diagonal set to zero.
+    ///
+    /// Element `(i, j)` is kept when `j >= i + k`, as in numpy's `triu`.
+    pub fn triu(&self, k: i64) -> Matrix {
+        let mut out = self.clone();
+        out.triu_mut(k);
+        out
+    }
+
+    /// Zeroes every element above the `k`-th diagonal in place. See `tril`.
+    pub fn tril_mut(&mut self, k: i64) {
+        self.fill_where(|i, j| j > i + k);
+    }
+
+    /// Zeroes every element below the `k`-th diagonal in place. See `triu`.
+    pub fn triu_mut(&mut self, k: i64) {
+        self.fill_where(|i, j| j < i + k);
+    }
+
+    // Zeroes the elements whose signed `(i, j)` index satisfies `pred`.
+    fn fill_where<F: Fn(i64, i64) -> bool>(&mut self, pred: F) {
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                if pred(i as i64, j as i64) {
+                    self.data[i * self.cols + j] = 0.;
+                }
+            }
+        }
+    }
+
+    /// Computes the trace of a square matrix.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the sum of the diagonal elements or an error if the matrix is not square.
+    pub fn trace(&self) -> Result<f64, LinalgError> {
+        if !self.is_square() {
+            return Err(LinalgError::NotSquare);
+        }
+        Ok((0..self.rows).map(|i| self[(i, i)]).sum())
+    }
+
+    /// Computes `trace(self * other)` without forming the product.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: Right factor of the product.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the sum of `self[(i, k)] * other[(k, i)]` over all `i` and
+    /// `k`, or an error if `other` is not `cols` x `rows`.
+    pub fn trace_of_product(&self, other: &Matrix) -> Result<f64, LinalgError> {
+        if other.shape() != (self.cols, self.rows) {
+            return Err(LinalgError::ShapeMismatch {
+                expected: (self.cols, self.rows),
+                found: other.shape(),
+            });
+        }
+        Ok((0..self.rows)
+            .map(|i| {
+                (0..self.cols)
+                    .map(|k| self[(i, k)] * other[(k, i)])
+                    .sum::<f64>()
+            })
+            .sum())
+    }
+
+    /// Returns `true` if the matrix has as many rows as columns.
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    /// Checks whether the matrix equals its transpose up to a tolerance.
+    ///
+    /// # Parameters
+    ///
+    /// - `tol`: Largest allowed absolute difference between `(i, j)` and `(j, i)`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the matrix is square and every mirrored pair differs by at most `tol`.
+    pub fn is_symmetric(&self, tol: f64) -> bool {
+        self.is_square()
+            && (0..self.rows).all(|i| (0..i).all(|j| (self[(i, j)] - self[(j, i)]).abs() <= tol))
+    }
+
+    /// Checks whether every element off the three central diagonals is negligible.
+    ///
+    /// # Parameters
+    ///
+    /// - `tol`: Largest absolute value treated as zero.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the matrix is square and every element with `|i - j| > 1` is at most `tol` in
+    /// absolute value.
+    pub fn is_tridiagonal(&self, tol: f64) -> bool {
+        self.is_square()
+            && (0..self.rows)
+                .all(|i| (0..self.cols).all(|j| i.abs_diff(j) <= 1 || self[(i, j)].abs() <= tol))
+    }
+
+    /// Computes the Frobenius norm of the matrix.
+    ///
+    /// # Returns
+    ///
+    /// The square root of the sum of the squares of every element.
+    pub fn frobenius_norm(&self) -> f64 {
+        self.data.iter().map(|x| x * x).sum::<f64>().sqrt()
+    }
+
+    /// Computes the Frobenius inner product with another matrix.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: Matrix of the same shape.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the sum of the elementwise products or an error if the shapes
+    /// differ.
+    pub fn frobenius_inner(&self, other: &Matrix) -> Result<f64, LinalgError> {
+        if self.shape() != other.shape() {
+            return Err(LinalgError::ShapeMismatch {
+                expected: self.shape(),
+                found: other.shape(),
+            });
+        }
+        Ok(self.data.iter().zip(&other.data).map(|(x, y)| x * y).sum())
+    }
+
+    /// Computes a norm of the matrix.
     ///
     /// # Parameters
     ///
-    /// - `n_rows`: Number of rows in the matrix.
-    /// - `n_cols`: Number of columns in the matrix.
-    /// - `val`: Scalar value to fill the matrix.
+    /// - `kind`: Which norm to compute.
     ///
     /// # Returns
     ///
-    /// A new `Matrix` with dimensions `n_rows` x `n_cols` filled with `val`.
-    pub fn from_scalar(n_rows: usize, n_cols: usize, val: f64) -> Self {
-        Matrix {
-            rows: n_rows,
-            cols: n_cols,
-            data: vec![val; n_cols * n_rows],
+    /// The requested norm. Empty matrices have norm `0.0`.
+    pub fn norm(&self, kind: Norm) -> f64 {
+        match kind {
+            Norm::One => (0..self.cols)
+                .map(|j| (0..self.rows).map(|i| self[(i, j)].abs()).sum::<f64>())
+                .fold(0., f64::max),
+            Norm::Inf => (0..self.rows)
+                .map(|i| (0..self.cols).map(|j| self[(i, j)].abs()).sum::<f64>())
+                .fold(0., f64::max),
+            Norm::Frobenius => self.frobenius_norm(),
         }
     }
 
-    /// Creates a new matrix from a 2D vector of floating-point numbers.
+    /// Adds two matrices without consuming them or panicking on a shape mismatch.
     ///
     /// # Parameters
     ///
-    /// - `n_rows`: Number of rows in the matrix.
-    /// - `n_cols`: Number of columns in the matrix.
-    /// - `data`: 2D vector containing matrix elements.
+    /// - `other`: Matrix with the same shape as `self`.
     ///
     /// # Returns
     ///
-    /// A Result containing either the created `Matrix` or an error message if dimensions are inconsistent.
-    pub fn from_2d_vec(n_rows: usize, n_cols: usize, data: Vec<Vec<f64>>) -> Result<Self, String> {
-        let mut data_formatted = Vec::<f64>::with_capacity(n_rows * n_cols);
-        if data.len() != n_rows {
-            return Err("Inconsistent row length".to_owned());
+    /// A Result containing either the elementwise sum or an error if the shapes differ.
+    pub fn checked_add(&self, other: &Matrix) -> Result<Matrix, LinalgError> {
+        if self.shape() != other.shape() {
+            return Err(LinalgError::ShapeMismatch {
+                expected: self.shape(),
+                found: other.shape(),
+            });
         }
+        Ok(self + other)
+    }
 
-        for row in data {
-            if row.len() != n_cols {
-                return Err("Inconsistent column length".to_owned());
-            }
-            data_formatted.extend(row);
+    /// Multiplies two matrices without consuming them or panicking on a dimension mismatch.
+    ///
+    /// # Parameters
+    ///
+    /// - `rhs`: Right-hand operand with as many rows as `self` has columns.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the `rows` x `rhs.cols` product or an error if
+    /// `self.cols != rhs.rows`.
+    pub fn checked_mul(&self, rhs: &Matrix) -> Result<Matrix, LinalgError> {
+        if self.cols != rhs.rows {
+            return Err(LinalgError::ShapeMismatch {
+                expected: (self.cols, rhs.cols),
+                found: rhs.shape(),
+            });
         }
+        Ok(self * rhs)
+    }
 
-        Ok(Matrix {
-            rows: n_rows,
-            cols: n_cols,
-            data: data_formatted,
+    /// Multiplies the matrix by a column vector given as a slice.
+    ///
+    /// # Parameters
+    ///
+    /// - `v`: Vector with one element per column of the matrix.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the product as a `Vec<f64>` of length `rows` or an error
+    /// if `v.len() != cols`.
+    pub fn mul_vec(&self, v: &[f64]) -> Result<Vec<f64>, LinalgError> {
+        if v.len() != self.cols {
+            return Err(LinalgError::LengthMismatch {
+                expected: self.cols,
+                found: v.len(),
+            });
+        }
+        Ok((0..self.rows)
+            .map(|i| (0..self.cols).map(|j| self[(i, j)] * v[j]).sum())
+            .collect())
+    }
+
+    /// Computes the Kronecker product with another matrix.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: Right-hand operand, which may have any shape.
+    ///
+    /// # Returns
+    ///
+    /// A `(rows * other.rows)` x `(cols * other.cols)` matrix made of copies of `other`, each scaled by
+    /// the corresponding element of `self`.
+    pub fn kron(&self, other: &Matrix) -> Matrix {
+        Matrix::from_fn(self.rows * other.rows, self.cols * other.cols, |i, j| {
+            self[(i / other.rows, j / other.cols)] * other[(i % other.rows, j % other.cols)]
         })
     }
 
-    /// Creates an identity matrix of a given size.
+    /// Computes the 2D cross-correlation of the matrix with a kernel.
+    ///
+    /// Output element `(i, j)` is the sum of `kernel[(a, b)] * self[(i + a, j + b)]` over the
+    /// kernel, with the kernel anchored so its centre (rounded towards the top-left for even
+    /// sizes) sits over `(i, j)` when `pad` is set. The kernel is not flipped, as is usual in
+    /// image processing.
     ///
     /// # Parameters
     ///
-    /// - `size`: Size of the square identity matrix (size x size).
+    /// - `kernel`: Non-empty weights to slide over the matrix.
+    /// - `pad`: If `true`, pad with zeros so the output has the same shape as `self`; otherwise
+    ///   only positions where the kernel fits entirely are kept.
     ///
     /// # Returns
     ///
-    /// An identity matrix of dimensions `size` x `size`.
-    pub fn identity(size: usize) -> Self {
-        let mut data = vec![0.0; size * size];
-        for i in 0..size {
-            data[i * size + i] = 1.0;
+    /// A Result containing either the correlated matrix or an error if the kernel is empty or,
+    /// without padding, larger than the matrix.
+    pub fn convolve2d(&self, kernel: &Matrix, pad: bool) -> Result<Matrix, LinalgError> {
+        let (kr, kc) = kernel.shape();
+        if kr == 0 || kc == 0 {
+            return Err(LinalgError::InvalidArgument(
+                "Convolution kernel must not be empty".to_string(),
+            ));
+        }
+        if pad {
+            let (off_r, off_c) = ((kr - 1) / 2, (kc - 1) / 2);
+            return Ok(Matrix::from_fn(self.rows, self.cols, |i, j| {
+                let mut sum = 0.;
+                for a in 0..kr {
+                    for b in 0..kc {
+                        let (r, c) = ((i + a).wrapping_sub(off_r), (j + b).wrapping_sub(off_c));
+                        if let Some(&x) = self.get(r, c) {
+                            sum += kernel[(a, b)] * x;
+                        }
+                    }
+                }
+                sum
+            }));
+        }
+        if kr > self.rows || kc > self.cols {
+            return Err(LinalgError::InvalidArgument(format!(
+                "Kernel of shape ({}, {}) does not fit in a ({}, {}) matrix without padding",
+                kr, kc, self.rows, self.cols
+            )));
+        }
+        Ok(Matrix::from_fn(
+            self.rows - kr + 1,
+            self.cols - kc + 1,
+            |i, j| {
+                let mut sum = 0.;
+                for a in 0..kr {
+                    for b in 0..kc {
+                        sum += kernel[(a, b)] * self[(i + a, j + b)];
+                    }
+                }
+                sum
+            },
+        ))
+    }
+
+    /// Swaps rows `i` and `k` in place. Equal indices leave the matrix unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is not less than `rows`.
+    pub fn swap_rows(&mut self, i: usize, k: usize) {
+        if i >= self.rows || k >= self.rows {
+            panic!(
+                "index out of bounds: the shape is ({}, {}) but the row indices are ({}, {}).",
+                self.rows, self.cols, i, k
+            );
+        }
+        if i == k {
+            return;
+        }
+        let (lo, hi) = (i.min(k), i.max(k));
+        let (head, tail) = self.data.split_at_mut(hi * self.cols);
+        head[lo * self.cols..(lo + 1) * self.cols].swap_with_slice(&mut tail[..self.cols]);
+    }
+
+    /// Multiplies every element of row `row` by `factor` in place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row >= rows`.
+    pub fn scale_row(&mut self, row: usize, factor: f64) {
+        for x in &mut self[row] {
+            *x *= factor;
+        }
+    }
+
+    /// Swaps columns `j` and `l` in place. Equal indices leave the matrix unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is not less than `cols`.
+    pub fn swap_cols(&mut self, j: usize, l: usize) {
+        if j >= self.cols || l >= self.cols {
+            panic!(
+                "index out of bounds: the shape is ({}, {}) but the column indices are ({}, {}).",
+                self.rows, self.cols, j, l
+            );
+        }
+        if j == l {
+            return;
+        }
+        for row in self.data.chunks_exact_mut(self.cols) {
+            row.swap(j, l);
+        }
+    }
+
+    /// Reorders the rows of the matrix so that row `k` of the result is row `perm[k]` of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `perm` is not a permutation of `0..rows`.
+    pub fn permute_rows(&self, perm: &[usize]) -> Matrix {
+        if let Err(e) = validate_permutation(perm, self.rows) {
+            panic!("{}", e);
+        }
+        let mut data = Vec::with_capacity(self.data.len());
+        for &p in perm {
+            data.extend_from_slice(&self.data[p * self.cols..(p + 1) * self.cols]);
         }
         Matrix {
-            rows: size,
-            cols: size,
+            rows: self.rows,
+            cols: self.cols,
             data,
         }
     }
 
-    /// Returns the shape of the matrix.
+    /// Reorders the columns of the matrix so that column `k` of the result is column `perm[k]` of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `perm` is not a permutation of `0..cols`.
+    pub fn permute_cols(&self, perm: &[usize]) -> Matrix {
+        if let Err(e) = validate_permutation(perm, self.cols) {
+            panic!("{}", e);
+        }
+        Matrix::from_fn(self.rows, self.cols, |i, j| self[(i, perm[j])])
+    }
+
+    /// Reverses the order of the columns.
+    pub fn fliplr(&self) -> Matrix {
+        Matrix::from_fn(self.rows, self.cols, |i, j| self[(i, self.cols - 1 - j)])
+    }
+
+    /// Reverses the order of the rows.
+    pub fn flipud(&self) -> Matrix {
+        Matrix::from_fn(self.rows, self.cols, |i, j| self[(self.rows - 1 - i, j)])
+    }
+
+    /// Rotates the matrix by 90 degrees counter-clockwise `k` times, like numpy's `rot90`.
+    ///
+    /// # Parameters
+    ///
+    /// - `k`: Number of quarter turns. Negative values rotate clockwise.
     ///
     /// # Returns
     ///
-    /// A tuple representing the matrix shape: (rows, cols)
-    pub fn shape(&self) -> (usize, usize) {
-        // Return the shape of the matrix in the form (rows, cols)
-        (self.rows, self.cols)
+    /// A new matrix, which is `cols` x `rows` when `k` is odd.
+    pub fn rot90(&self, k: i32) -> Matrix {
+        let (r, c) = (self.rows, self.cols);
+        match k.rem_euclid(4) {
+            0 => self.clone(),
+            1 => Matrix::from_fn(c, r, |i, j| self[(j, c - 1 - i)]),
+            2 => Matrix::from_fn(r, c, |i, j| self[(r - 1 - i, c - 1 - j)]),
+            _ => Matrix::from_fn(c, r, |i, j| self[(r - 1 - j, i)]),
+        }
     }
 
-    /// Transposes the matrix.
+    /// Applies a function to every element of the matrix.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: Function applied to each element.
     ///
     /// # Returns
     ///
-    /// A new `Matrix` which is the transpose of the current matrix.
-    pub fn transpose(self) -> Self {
-        let mut ret = Matrix {
-            rows: self.cols,
-            cols: self.rows,
-            data: vec![0.; self.cols * self.rows],
-        };
-        for i in 0..ret.rows {
-            for j in 0..ret.cols {
-                ret[(i, j)] = self[(j, i)];
+    /// A new `Matrix` of the same shape with `f` applied elementwise.
+    pub fn map<F: Fn(f64) -> f64>(&self, f: F) -> Matrix {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().map(|&x| f(x)).collect(),
+        }
+    }
+
+    /// Applies a function to every element of the matrix in place.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: Function applied to each element.
+    pub fn map_inplace<F: Fn(f64) -> f64>(&mut self, f: F) {
+        for x in &mut self.data {
+            *x = f(*x);
+        }
+    }
+
+    /// Combines two matrices of the same shape elementwise.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: Matrix with the same shape as `self`.
+    /// - `f`: Function called as `f(self[(i, j)], other[(i, j)])`.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the combined matrix or an error if the shapes differ.
+    pub fn zip_map<F: Fn(f64, f64) -> f64>(
+        &self,
+        other: &Matrix,
+        f: F,
+    ) -> Result<Matrix, LinalgError> {
+        if self.shape() != other.shape() {
+            return Err(LinalgError::ShapeMismatch {
+                expected: self.shape(),
+                found: other.shape(),
+            });
+        }
+        Ok(Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self
+                .data
+                .iter()
+                .zip(&other.data)
+                .map(|(&x, &y)| f(x, y))
+                .collect(),
+        })
+    }
+
+    /// Applies the rectified linear unit `max(0, x)` elementwise.
+    pub fn relu(&self) -> Matrix {
+        self.map(|x| x.max(0.))
+    }
+
+    /// Applies the logistic sigmoid `1 / (1 + e^-x)` elementwise.
+    pub fn sigmoid(&self) -> Matrix {
+        self.map(|x| 1. / (1. + (-x).exp()))
+    }
+
+    /// Repeats the matrix in a grid, like numpy's `tile`.
+    ///
+    /// # Parameters
+    ///
+    /// - `reps_rows`: Number of copies stacked vertically.
+    /// - `reps_cols`: Number of copies placed side by side.
+    ///
+    /// # Returns
+    ///
+    /// A `(rows * reps_rows)` x `(cols * reps_cols)` matrix. Zero repetitions give an empty dimension.
+    pub fn tile(&self, reps_rows: usize, reps_cols: usize) -> Matrix {
+        let mut data = Vec::with_capacity(self.data.len() * reps_rows * reps_cols);
+        for _ in 0..reps_rows {
+            for i in 0..self.rows {
+                let row = &self.data[i * self.cols..(i + 1) * self.cols];
+                for _ in 0..reps_cols {
+                    data.extend_from_slice(row);
+                }
             }
         }
-        ret
+        Matrix {
+            rows: self.rows * reps_rows,
+            cols: self.cols * reps_cols,
+            data,
+        }
+    }
+
+    /// Repeats each element into an `r` x `c` block.
+    ///
+    /// Equivalent to `self.kron(&Matrix::ones(r, c))`.
+    ///
+    /// # Returns
+    ///
+    /// A `(rows * r)` x `(cols * c)` matrix.
+    pub fn repeat_elements(&self, r: usize, c: usize) -> Matrix {
+        Matrix::from_fn(self.rows * r, self.cols * c, |i, j| self[(i / r, j / c)])
     }
 
     /// Raises a square matrix to a given power
@@ -127,7 +1514,7 @@ impl Matrix {
     ///
     /// Panics if the matrix is not square (`self.rows != self.cols`).
     pub fn pow(&self, pow: i64) -> Self {
-        if self.rows != self.cols {
+        if !self.is_square() {
             panic!("Can only raise square matrices to a power.");
         }
         if pow == 0 {
@@ -141,11 +1528,11 @@ impl Matrix {
 
     fn pow_helper(mat: Self, pow: i64) -> Self {
         if pow == 0 {
-            return Self::identity(mat.rows);
+            Self::identity(mat.rows)
         } else if pow % 2 == 0 {
-            return Self::pow_helper(mat.clone() * mat.clone(), pow / 2);
+            Self::pow_helper(mat.clone() * mat.clone(), pow / 2)
         } else {
-            return mat.clone() * Self::pow_helper(mat.clone() * mat.clone(), pow / 2);
+            mat.clone() * Self::pow_helper(mat.clone() * mat.clone(), pow / 2)
         }
     }
 }
@@ -154,7 +1541,7 @@ impl Index<(usize, usize)> for Matrix {
     type Output = f64;
     fn index(&self, (i, j): (usize, usize)) -> &f64 {
         if i < self.rows && j < self.cols {
-            return &self.data[i * self.cols + j];
+            &self.data[i * self.cols + j]
         } else {
             panic!(
                 "index out of bounds: the shape is ({}, {}) but the index is ({}, {}).",
@@ -167,7 +1554,7 @@ impl Index<(usize, usize)> for Matrix {
 impl IndexMut<(usize, usize)> for Matrix {
     fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut f64 {
         if i < self.rows && j < self.cols {
-            return &mut self.data[i * self.cols + j];
+            &mut self.data[i * self.cols + j]
         } else {
             panic!(
                 "index out of bounds: the shape is ({}, {}) but the index is ({}, {}).",
@@ -177,6 +1564,34 @@ impl IndexMut<(usize, usize)> for Matrix {
     }
 }
 
+/// Indexes a whole row, so `m[i][j]` is the same element as `m[(i, j)]`.
+impl Index<usize> for Matrix {
+    type Output = [f64];
+    fn index(&self, i: usize) -> &[f64] {
+        if i < self.rows {
+            &self.data[i * self.cols..(i + 1) * self.cols]
+        } else {
+            panic!(
+                "index out of bounds: the shape is ({}, {}) but the row index is {}.",
+                self.rows, self.cols, i
+            )
+        }
+    }
+}
+
+impl IndexMut<usize> for Matrix {
+    fn index_mut(&mut self, i: usize) -> &mut [f64] {
+        if i < self.rows {
+            &mut self.data[i * self.cols..(i + 1) * self.cols]
+        } else {
+            panic!(
+                "index out of bounds: the shape is ({}, {}) but the row index is {}.",
+                self.rows, self.cols, i
+            )
+        }
+    }
+}
+
 impl Clone for Matrix {
     fn clone(&self) -> Self {
         Matrix {
@@ -211,7 +1626,7 @@ impl Add for Matrix {
             panic!("Matrices of different shapes cannot be added together. Left({}, {}), Right({}, {})", 
                    self.rows, self.cols, other.rows, other.cols);
         } else {
-            return Matrix {
+            Matrix {
                 rows: self.rows,
                 cols: self.cols,
                 data: self
@@ -220,7 +1635,39 @@ impl Add for Matrix {
                     .zip(other.data.iter())
                     .map(|(x, y)| x + y)
                     .collect(),
-            };
+            }
+        }
+    }
+}
+
+impl Add<&Matrix> for &Matrix {
+    type Output = Matrix;
+    fn add(self, other: &Matrix) -> Self::Output {
+        if self.rows != other.rows || self.cols != other.cols {
+            panic!("Matrices of different shapes cannot be added together. Left({}, {}), Right({}, {})",
+                   self.rows, self.cols, other.rows, other.cols);
+        }
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self
+                .data
+                .iter()
+                .zip(other.data.iter())
+                .map(|(x, y)| x + y)
+                .collect(),
+        }
+    }
+}
+
+impl AddAssign for Matrix {
+    fn add_assign(&mut self, rhs: Matrix) {
+        if self.rows != rhs.rows || self.cols != rhs.cols {
+            panic!("Matrices of different shapes cannot be added together. Left({}, {}), Right({}, {})",
+                   self.rows, self.cols, rhs.rows, rhs.cols);
+        }
+        for (x, y) in self.data.iter_mut().zip(rhs.data.iter()) {
+            *x += y;
         }
     }
 }
@@ -229,6 +1676,13 @@ impl Add for Matrix {
 impl Mul for Matrix {
     type Output = Matrix;
     fn mul(self, rhs: Matrix) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl Mul<&Matrix> for &Matrix {
+    type Output = Matrix;
+    fn mul(self, rhs: &Matrix) -> Self::Output {
         // Check that dims are correct
         if self.cols != rhs.rows {
             panic!(
@@ -236,15 +1690,18 @@ impl Mul for Matrix {
                 self.rows, self.cols, rhs.rows, rhs.cols
             );
         }
-        let mut out = Matrix::from_scalar(self.rows, rhs.cols, 0.);
+        let mut out = Matrix::zeros(self.rows, rhs.cols);
 
         for i in 0..out.rows {
             for j in 0..out.cols {
                 let mut el = 0.;
-                for k in 0..self.cols {
-                    el += self[(i, k)] * rhs[(k, j)];
+                // SAFETY: `i < self.rows`, `j < rhs.cols` and `k < self.cols == rhs.rows`.
+                unsafe {
+                    for k in 0..self.cols {
+                        el += self.get_unchecked(i, k) * rhs.get_unchecked(k, j);
+                    }
+                    *out.get_unchecked_mut(i, j) = el;
                 }
-                out[(i, j)] = el;
             }
         }
 
@@ -260,15 +1717,18 @@ impl MulAssign for Matrix {
                 self.rows, self.cols, rhs.rows, rhs.cols
             );
         }
-        let mut out = Matrix::from_scalar(self.rows, rhs.cols, 0.);
+        let mut out = Matrix::zeros(self.rows, rhs.cols);
 
         for i in 0..out.rows {
             for j in 0..out.cols {
                 let mut el = 0.;
-                for k in 0..self.cols {
-                    el += self[(i, k)] * rhs[(k, j)];
+                // SAFETY: `i < self.rows`, `j < rhs.cols` and `k < self.cols == rhs.rows`.
+                unsafe {
+                    for k in 0..self.cols {
+                        el += self.get_unchecked(i, k) * rhs.get_unchecked(k, j);
+                    }
+                    *out.get_unchecked_mut(i, j) = el;
                 }
-                out[(i, j)] = el;
             }
         }
 
@@ -294,41 +1754,178 @@ impl Mul<f64> for Matrix {
     }
 }
 
-fn number_of_digits(number: f64) -> i64 {
-    let tol = 1e-8;
-    if number.abs() < tol {
-        return 1;
+impl Neg for Matrix {
+    type Output = Matrix;
+    fn neg(mut self) -> Self::Output {
+        for el in &mut self.data {
+            *el = -*el;
+        }
+        self
+    }
+}
+
+// Summing and multiplying iterators of matrices. An empty iterator has no
+// shape to give the result, so it panics.
+impl Sum for Matrix {
+    fn sum<I: Iterator<Item = Matrix>>(mut iter: I) -> Self {
+        let first = iter
+            .next()
+            .expect("Cannot sum an empty iterator of matrices: the shape is unknown.");
+        iter.fold(first, |mut acc, m| {
+            acc += m;
+            acc
+        })
+    }
+}
+
+impl<'a> Sum<&'a Matrix> for Matrix {
+    fn sum<I: Iterator<Item = &'a Matrix>>(mut iter: I) -> Self {
+        let first = iter
+            .next()
+            .expect("Cannot sum an empty iterator of matrices: the shape is unknown.");
+        iter.fold(first.clone(), |acc, m| &acc + m)
+    }
+}
+
+/// Multiplies the matrices left to right, so `[a, b, c]` gives `a * b * c`.
+impl Product for Matrix {
+    fn product<I: Iterator<Item = Matrix>>(mut iter: I) -> Self {
+        let first = iter
+            .next()
+            .expect("Cannot multiply an empty iterator of matrices: the shape is unknown.");
+        iter.fold(first, |acc, m| &acc * &m)
+    }
+}
+
+impl<'a> Product<&'a Matrix> for Matrix {
+    fn product<I: Iterator<Item = &'a Matrix>>(mut iter: I) -> Self {
+        let first = iter
+            .next()
+            .expect("Cannot multiply an empty iterator of matrices: the shape is unknown.");
+        iter.fold(first.clone(), |acc, m| &acc * m)
+    }
+}
+
+fn check_index(index: usize, bound: usize) -> Result<(), LinalgError> {
+    if index >= bound {
+        return Err(LinalgError::IndexOutOfBounds { index, bound });
+    }
+    Ok(())
+}
+
+fn validate_permutation(perm: &[usize], n: usize) -> Result<(), LinalgError> {
+    if perm.len() != n {
+        return Err(LinalgError::LengthMismatch {
+            expected: n,
+            found: perm.len(),
+        });
+    }
+    let mut seen = vec![false; n];
+    for (k, &p) in perm.iter().enumerate() {
+        if p >= n {
+            return Err(LinalgError::IndexOutOfBounds { index: p, bound: n });
+        }
+        if seen[p] {
+            return Err(LinalgError::InvalidArgument(format!(
+                "Permutation entry {} at position {} is repeated",
+                p, k
+            )));
+        }
+        seen[p] = true;
+    }
+    Ok(())
+}
+
+fn linspace_values(start: f64, stop: f64, num: usize) -> Vec<f64> {
+    match num {
+        0 => vec![],
+        1 => vec![start],
+        _ => {
+            let intervals = (num - 1) as f64;
+            let mut values: Vec<f64> = (0..num)
+                .map(|i| start + (stop - start) * (i as f64 / intervals))
+                .collect();
+            values[num - 1] = stop;
+            values
+        }
+    }
+}
+
+fn arange_values(start: f64, stop: f64, step: f64) -> Result<Vec<f64>, LinalgError> {
+    if !(start.is_finite() && stop.is_finite() && step.is_finite()) {
+        return Err(LinalgError::InvalidArgument(format!(
+            "Start {}, stop {} and step {} must all be finite",
+            start, stop, step
+        )));
+    }
+    if step == 0. {
+        return Err(LinalgError::InvalidArgument(
+            "Step must be non-zero".to_owned(),
+        ));
     }
-    return (number.log(10.0) + tol).floor() as i64 + 1;
+    if (stop - start) * step < 0. {
+        return Err(LinalgError::InvalidArgument(format!(
+            "Step {} moves away from stop {} when starting at {}",
+            step, stop, start
+        )));
+    }
+    let count = ((stop - start) / step).ceil() as usize;
+    Ok((0..count)
+        .map(|i| start + i as f64 * step)
+        .take_while(|&x| if step > 0. { x < stop } else { x > stop })
+        .collect())
+}
+
+// Width of the part of a formatted number before its decimal point, counting
+// any sign. Taking it from the text rather than the value keeps values that
+// print as "-0" or "-0.00" aligned with the rest.
+fn integer_width(text: &str) -> usize {
+    text.find('.').unwrap_or(text.len())
 }
 
 impl Display for Matrix {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let sep = " ";
-        let tol = 1e-8;
         let mut s = "".to_string();
-        let mut max_num_len = 0;
 
-        for row in 0..self.rows {
-            for col in 0..self.cols {
-                let elem = self[(row, col)];
-                max_num_len = max(number_of_digits(elem), max_num_len);
-            }
-        }
+        // Each element as it will be printed, so rounding to the formatter's
+        // precision is reflected in the column widths.
+        let texts: Vec<String> = self
+            .data
+            .iter()
+            .map(|elem| match f.precision() {
+                Some(p) => format!("{:.*}", p, elem),
+                None => format!("{}", elem),
+            })
+            .collect();
+        let max_num_len = texts.iter().map(|t| integer_width(t)).max().unwrap_or(0);
+        // Width of the decimal point and fraction in each column, so the
+        // decimal points of a column line up.
+        let frac_lens: Vec<usize> = (0..self.cols)
+            .map(|col| {
+                (0..self.rows)
+                    .map(|row| {
+                        let text = &texts[row * self.cols + col];
+                        text.len() - integer_width(text)
+                    })
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
 
         for row in 0..self.rows {
             for col in 0..self.cols {
-                let elem = self[(row, col)];
-                let mut num_len = number_of_digits(elem);
-                if elem.abs() < tol {
-                    num_len = 1
-                }
+                let text = &texts[row * self.cols + col];
+                let num_len = integer_width(text);
                 for _ in 0..(max_num_len - num_len) {
-                    s.push_str(&sep);
+                    s.push_str(sep);
                 }
-                s.push_str(&format!("{}", elem)[..]);
+                s.push_str(text);
                 if col != self.cols - 1 {
-                    s.push_str(&sep);
+                    for _ in 0..(frac_lens[col] - (text.len() - num_len)) {
+                        s.push_str(sep);
+                    }
+                    s.push_str(sep);
                 }
             }
             s.push('\n');
@@ -0,0 +1,40 @@
+use rayon::iter::Either;
+use rayon::prelude::*;
+use rayon::slice::{Iter, IterMut};
+
+use crate::Matrix;
+
+impl Matrix {
+    /// Iterates in parallel over references to the elements.
+    ///
+    /// The underlying order is row-major, which indexed adaptors such as
+    /// `enumerate` and `collect` preserve.
+    pub fn par_iter(&self) -> Iter<'_, f64> {
+        self.data.par_iter()
+    }
+
+    /// Iterates in parallel over mutable references to the elements.
+    pub fn par_iter_mut(&mut self) -> IterMut<'_, f64> {
+        self.data.par_iter_mut()
+    }
+
+    /// Iterates in parallel over the rows as mutable slices, so each task owns whole rows.
+    ///
+    /// Matrices with no columns yield `rows` empty slices, like `rows_mut`.
+    pub fn par_rows_mut(&mut self) -> impl IndexedParallelIterator<Item = &mut [f64]> {
+        if self.cols == 0 {
+            Either::Left((0..self.rows).into_par_iter().map(|_| Default::default()))
+        } else {
+            Either::Right(self.data.par_chunks_exact_mut(self.cols))
+        }
+    }
+
+    /// Applies a function to every element of the matrix in place, in parallel.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: Function applied to each element.
+    pub fn par_map_inplace<F: Fn(f64) -> f64 + Sync + Send>(&mut self, f: F) {
+        self.data.par_iter_mut().for_each(|x| *x = f(*x));
+    }
+}
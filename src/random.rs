@@ -0,0 +1,159 @@
+use rand::Rng;
+
+use crate::{LinalgError, Matrix, Svd};
+
+impl Matrix {
+    /// Creates a matrix with elements drawn uniformly from `[lo, hi)`.
+    ///
+    /// # Parameters
+    ///
+    /// - `n_rows`: Number of rows in the matrix.
+    /// - `n_cols`: Number of columns in the matrix.
+    /// - `lo`: Inclusive lower bound of the distribution.
+    /// - `hi`: Exclusive upper bound of the distribution.
+    /// - `rng`: Random number generator to sample from.
+    ///
+    /// # Returns
+    ///
+    /// A new `Matrix` with dimensions `n_rows` x `n_cols`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lo >= hi`.
+    pub fn random_uniform(
+        n_rows: usize,
+        n_cols: usize,
+        lo: f64,
+        hi: f64,
+        rng: &mut impl Rng,
+    ) -> Self {
+        Self::from_fn(n_rows, n_cols, |_, _| rng.gen_range(lo..hi))
+    }
+
+    /// Creates a matrix with elements drawn from the standard normal distribution.
+    ///
+    /// # Parameters
+    ///
+    /// - `n_rows`: Number of rows in the matrix.
+    /// - `n_cols`: Number of columns in the matrix.
+    /// - `rng`: Random number generator to sample from.
+    ///
+    /// # Returns
+    ///
+    /// A new `Matrix` with dimensions `n_rows` x `n_cols`.
+    pub fn random_standard_normal(n_rows: usize, n_cols: usize, rng: &mut impl Rng) -> Self {
+        Self::from_fn(n_rows, n_cols, |_, _| standard_normal(rng))
+    }
+
+    /// Creates a random orthogonal matrix, distributed uniformly (Haar measure).
+    ///
+    /// Computed from the QR decomposition of a Gaussian matrix, with the
+    /// columns of `Q` scaled by the signs of the diagonal of `R`.
+    ///
+    /// # Parameters
+    ///
+    /// - `n`: Size of the square matrix.
+    /// - `rng`: Random number generator to sample from.
+    ///
+    /// # Returns
+    ///
+    /// An `n` x `n` orthogonal matrix.
+    pub fn random_orthogonal(n: usize, rng: &mut impl Rng) -> Self {
+        let (q, r) = Self::random_standard_normal(n, n, rng)
+            .qr()
+            .expect("square matrices always have a QR decomposition");
+        Self::from_fn(n, n, |i, j| {
+            if r[(j, j)] < 0. {
+                -q[(i, j)]
+            } else {
+                q[(i, j)]
+            }
+        })
+    }
+
+    /// Creates a random symmetric positive definite matrix.
+    ///
+    /// Computed as `A * A^T + n * I` for a Gaussian matrix `A`; the diagonal
+    /// shift keeps the matrix well away from singular.
+    ///
+    /// # Parameters
+    ///
+    /// - `n`: Size of the square matrix.
+    /// - `rng`: Random number generator to sample from.
+    ///
+    /// # Returns
+    ///
+    /// An `n` x `n` symmetric positive definite matrix.
+    pub fn random_spd(n: usize, rng: &mut impl Rng) -> Self {
+        let a = Self::random_standard_normal(n, n, rng);
+        let mut spd = &a * &a.clone().transpose();
+        for i in 0..n {
+            spd[(i, i)] += n as f64;
+        }
+        // Rounding in the product can leave the result very slightly asymmetric.
+        for i in 0..n {
+            for j in 0..i {
+                spd[(i, j)] = spd[(j, i)];
+            }
+        }
+        spd
+    }
+
+    /// Computes an approximate truncated SVD with the randomized range finder.
+    ///
+    /// Follows Halko, Martinsson and Tropp: the matrix is multiplied by a
+    /// Gaussian test matrix with `k + oversample` columns, optionally refined by
+    /// `n_iter` power iterations, and an orthonormal basis `Q` of the sample is
+    /// taken by QR. The small matrix `QᵀA` is then decomposed exactly.
+    ///
+    /// # Parameters
+    ///
+    /// - `k`: Number of singular triplets to return.
+    /// - `oversample`: Extra sample columns, which improve accuracy. About 5 to 10 is typical.
+    /// - `n_iter`: Number of power iterations, useful when the spectrum decays slowly.
+    /// - `rng`: Random number generator to draw the test matrix from.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either an `Svd` with `k` singular values, `rows` x `k` `u` and `k` x
+    /// `cols` `vt`, or an error if `k` is zero or larger than `min(rows, cols)`.
+    pub fn randomized_svd(
+        &self,
+        k: usize,
+        oversample: usize,
+        n_iter: usize,
+        rng: &mut impl Rng,
+    ) -> Result<Svd, LinalgError> {
+        let (m, n) = self.shape();
+        if k == 0 || k > m.min(n) {
+            return Err(LinalgError::InvalidArgument(format!(
+                "Rank {} is not within 1..={} for a ({}, {}) matrix",
+                k,
+                m.min(n),
+                m,
+                n
+            )));
+        }
+        let l = (k + oversample).min(m.min(n));
+        let at = self.clone().transpose();
+        let omega = Self::random_standard_normal(n, l, rng);
+        let (mut q, _) = (self * &omega).qr()?;
+        for _ in 0..n_iter {
+            let (z, _) = (&at * &q).qr()?;
+            q = (self * &z).qr()?.0;
+        }
+        let small = (&q.clone().transpose() * self).svd()?;
+        Ok(Svd {
+            u: (&q * &small.u).submatrix(0..m, 0..k)?,
+            s: small.s[..k].to_vec(),
+            vt: small.vt.submatrix(0..k, 0..n)?,
+        })
+    }
+}
+
+// Box-Muller transform. `u1` is drawn from (0, 1] so the logarithm is finite.
+fn standard_normal(rng: &mut impl Rng) -> f64 {
+    let u1: f64 = 1. - rng.gen::<f64>();
+    let u2: f64 = rng.gen();
+    (-2. * u1.ln()).sqrt() * (2. * std::f64::consts::PI * u2).cos()
+}
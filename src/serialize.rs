@@ -0,0 +1,33 @@
+use serde::de::Error;
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::Matrix;
+
+// Unvalidated form of a serialized matrix.
+#[derive(Deserialize)]
+#[serde(rename = "Matrix")]
+struct RawMatrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<f64>,
+}
+
+/// Serializes the matrix as a struct with `rows`, `cols` and row-major `data` fields.
+impl Serialize for Matrix {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Matrix", 3)?;
+        state.serialize_field("rows", &self.rows)?;
+        state.serialize_field("cols", &self.cols)?;
+        state.serialize_field("data", &self.data)?;
+        state.end()
+    }
+}
+
+/// Deserializes a matrix, failing if `data` does not have `rows * cols` elements.
+impl<'de> Deserialize<'de> for Matrix {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = RawMatrix::deserialize(deserializer)?;
+        Matrix::from_vec(raw.rows, raw.cols, raw.data).map_err(D::Error::custom)
+    }
+}
@@ -0,0 +1,138 @@
+use std::ops::{Index, IndexMut, Mul};
+
+use crate::{LinalgError, Matrix};
+
+/// A basic column vector representation
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vector {
+    data: Vec<f64>,
+}
+
+impl Vector {
+    /// Creates a new vector from its elements.
+    pub fn new(data: Vec<f64>) -> Self {
+        Vector { data }
+    }
+
+    /// Returns the number of elements in the vector.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if the vector has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the elements of the vector as a slice.
+    pub fn as_slice(&self) -> &[f64] {
+        &self.data
+    }
+
+    /// Computes the dot product with another vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors have different lengths.
+    pub fn dot(&self, other: &Vector) -> f64 {
+        if self.len() != other.len() {
+            panic!(
+                "Vectors of different lengths cannot be dotted together. Left: {}, Right: {}",
+                self.len(),
+                other.len()
+            );
+        }
+        self.data
+            .iter()
+            .zip(other.data.iter())
+            .map(|(x, y)| x * y)
+            .sum()
+    }
+
+    /// Computes the Euclidean norm of the vector.
+    pub fn norm(&self) -> f64 {
+        self.dot(self).sqrt()
+    }
+}
+
+impl From<Vec<f64>> for Vector {
+    fn from(data: Vec<f64>) -> Self {
+        Vector { data }
+    }
+}
+
+/// Converts the vector into an n x 1 column matrix.
+impl From<Vector> for Matrix {
+    fn from(v: Vector) -> Self {
+        Matrix {
+            rows: v.data.len(),
+            cols: 1,
+            data: v.data,
+        }
+    }
+}
+
+/// Converts an n x 1 column matrix into a vector.
+impl TryFrom<Matrix> for Vector {
+    type Error = LinalgError;
+    fn try_from(mat: Matrix) -> Result<Self, Self::Error> {
+        if mat.cols != 1 {
+            return Err(LinalgError::ShapeMismatch {
+                expected: (mat.rows, 1),
+                found: mat.shape(),
+            });
+        }
+        Ok(Vector { data: mat.data })
+    }
+}
+
+impl Index<usize> for Vector {
+    type Output = f64;
+    fn index(&self, i: usize) -> &f64 {
+        &self.data[i]
+    }
+}
+
+impl IndexMut<usize> for Vector {
+    fn index_mut(&mut self, i: usize) -> &mut f64 {
+        &mut self.data[i]
+    }
+}
+
+// Scalar Multiplication
+impl Mul<Vector> for f64 {
+    type Output = Vector;
+    fn mul(self, rhs: Vector) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Mul<f64> for Vector {
+    type Output = Vector;
+    fn mul(mut self, rhs: f64) -> Self::Output {
+        for el in &mut self.data {
+            *el *= rhs;
+        }
+        self
+    }
+}
+
+// Matrix-Vector Multiplication
+impl Mul<Vector> for Matrix {
+    type Output = Vector;
+    fn mul(self, rhs: Vector) -> Self::Output {
+        if self.cols != rhs.len() {
+            panic!(
+                "Matrix cols must be same as vector length to multiply. Matrix: ({},{}), Vector: {}",
+                self.rows,
+                self.cols,
+                rhs.len()
+            );
+        }
+        Vector {
+            data: (0..self.rows)
+                .map(|i| (0..self.cols).map(|j| self[(i, j)] * rhs[j]).sum())
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,299 @@
+use std::ops::{Index, IndexMut, Mul, Range};
+
+use crate::block::check_range;
+use crate::{LinalgError, Matrix};
+
+/// A borrowed, read-only rectangular block of a `Matrix`.
+///
+/// The view stores a reference to the parent buffer together with the offset of its first
+/// element, its shape and the row stride of the parent, so creating one never copies.
+#[derive(Debug, Clone, Copy)]
+pub struct MatrixView<'a> {
+    data: &'a [f64],
+    offset: usize,
+    rows: usize,
+    cols: usize,
+    stride: usize,
+}
+
+impl<'a> MatrixView<'a> {
+    /// Returns the shape of the view.
+    ///
+    /// # Returns
+    ///
+    /// A tuple representing the view shape: (rows, cols)
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Creates a view of a block of this view, relative to its top-left corner.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either range is reversed or extends past the view.
+    pub fn view(&self, rows: Range<usize>, cols: Range<usize>) -> MatrixView<'a> {
+        if let Err(e) =
+            check_range("Row", &rows, self.rows).and(check_range("Column", &cols, self.cols))
+        {
+            panic!("{}", e);
+        }
+        MatrixView {
+            data: self.data,
+            offset: self.offset + rows.start * self.stride + cols.start,
+            rows: rows.len(),
+            cols: cols.len(),
+            stride: self.stride,
+        }
+    }
+
+    /// Copies the viewed elements into a new matrix.
+    ///
+    /// # Returns
+    ///
+    /// A `rows` x `cols` matrix with the same elements as the view.
+    pub fn to_matrix(&self) -> Matrix {
+        Matrix::from_fn(self.rows, self.cols, |i, j| self[(i, j)])
+    }
+}
+
+/// A borrowed, writable rectangular block of a `Matrix`.
+///
+/// Writes go straight to the parent matrix. The view borrows the parent mutably, so two
+/// overlapping mutable views cannot coexist; use `split_rows_mut` for disjoint halves.
+#[derive(Debug)]
+pub struct MatrixViewMut<'a> {
+    data: &'a mut [f64],
+    offset: usize,
+    rows: usize,
+    cols: usize,
+    stride: usize,
+}
+
+impl<'a> MatrixViewMut<'a> {
+    /// Returns the shape of the view.
+    ///
+    /// # Returns
+    ///
+    /// A tuple representing the view shape: (rows, cols)
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Sets every element of the view to `val`.
+    pub fn fill(&mut self, val: f64) {
+        for i in 0..self.rows {
+            let start = self.offset + i * self.stride;
+            self.data[start..start + self.cols].fill(val);
+        }
+    }
+
+    /// Overwrites the view with the elements of `src`.
+    ///
+    /// # Parameters
+    ///
+    /// - `src`: A `&Matrix` or `MatrixView` with the same shape as the view.
+    ///
+    /// # Returns
+    ///
+    /// A Result that is an error if the shapes differ, in which case nothing is written.
+    pub fn copy_from<'b>(&mut self, src: impl Into<MatrixView<'b>>) -> Result<(), LinalgError> {
+        self.zip_apply(src.into(), |dst, x| *dst = x)
+    }
+
+    /// Adds the elements of `src` to the view in place.
+    ///
+    /// # Parameters
+    ///
+    /// - `src`: A `&Matrix` or `MatrixView` with the same shape as the view.
+    ///
+    /// # Returns
+    ///
+    /// A Result that is an error if the shapes differ, in which case nothing is written.
+    pub fn add_assign_from<'b>(
+        &mut self,
+        src: impl Into<MatrixView<'b>>,
+    ) -> Result<(), LinalgError> {
+        self.zip_apply(src.into(), |dst, x| *dst += x)
+    }
+
+    /// Splits the view into the rows above `at` and the rows from `at` on.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of two disjoint mutable views with `at` and `rows - at` rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > rows`.
+    pub fn split_rows_mut(self, at: usize) -> (MatrixViewMut<'a>, MatrixViewMut<'a>) {
+        if at > self.rows {
+            panic!("Cannot split a view with {} rows at row {}.", self.rows, at);
+        }
+        let split = (self.offset + at * self.stride).min(self.data.len());
+        let (top, bottom) = self.data.split_at_mut(split);
+        (
+            MatrixViewMut {
+                data: top,
+                offset: self.offset,
+                rows: at,
+                cols: self.cols,
+                stride: self.stride,
+            },
+            MatrixViewMut {
+                data: bottom,
+                offset: self.offset + at * self.stride - split,
+                rows: self.rows - at,
+                cols: self.cols,
+                stride: self.stride,
+            },
+        )
+    }
+
+    fn zip_apply<F: Fn(&mut f64, f64)>(
+        &mut self,
+        src: MatrixView<'_>,
+        f: F,
+    ) -> Result<(), LinalgError> {
+        if src.shape() != self.shape() {
+            return Err(LinalgError::ShapeMismatch {
+                expected: self.shape(),
+                found: src.shape(),
+            });
+        }
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                f(&mut self[(i, j)], src[(i, j)]);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Matrix {
+    /// Creates a read-only view of a block of the matrix without copying it.
+    ///
+    /// # Parameters
+    ///
+    /// - `rows`: Range of row indices covered by the view.
+    /// - `cols`: Range of column indices covered by the view.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either range is reversed or extends past the matrix.
+    pub fn view(&self, rows: Range<usize>, cols: Range<usize>) -> MatrixView<'_> {
+        MatrixView::from(self).view(rows, cols)
+    }
+
+    /// Creates a writable view of a block of the matrix without copying it.
+    ///
+    /// # Parameters
+    ///
+    /// - `rows`: Range of row indices covered by the view.
+    /// - `cols`: Range of column indices covered by the view.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either range is reversed or extends past the matrix.
+    pub fn view_mut(&mut self, rows: Range<usize>, cols: Range<usize>) -> MatrixViewMut<'_> {
+        if let Err(e) =
+            check_range("Row", &rows, self.rows).and(check_range("Column", &cols, self.cols))
+        {
+            panic!("{}", e);
+        }
+        MatrixViewMut {
+            offset: rows.start * self.cols + cols.start,
+            rows: rows.len(),
+            cols: cols.len(),
+            stride: self.cols,
+            data: &mut self.data,
+        }
+    }
+}
+
+/// Views the whole matrix.
+impl<'a> From<&'a Matrix> for MatrixView<'a> {
+    fn from(mat: &'a Matrix) -> Self {
+        MatrixView {
+            data: &mat.data,
+            offset: 0,
+            rows: mat.rows,
+            cols: mat.cols,
+            stride: mat.cols,
+        }
+    }
+}
+
+impl Index<(usize, usize)> for MatrixView<'_> {
+    type Output = f64;
+    fn index(&self, (i, j): (usize, usize)) -> &f64 {
+        if i < self.rows && j < self.cols {
+            &self.data[self.offset + i * self.stride + j]
+        } else {
+            panic!(
+                "index out of bounds: the shape is ({}, {}) but the index is ({}, {}).",
+                self.rows, self.cols, i, j
+            )
+        }
+    }
+}
+
+impl Index<(usize, usize)> for MatrixViewMut<'_> {
+    type Output = f64;
+    fn index(&self, (i, j): (usize, usize)) -> &f64 {
+        if i < self.rows && j < self.cols {
+            &self.data[self.offset + i * self.stride + j]
+        } else {
+            panic!(
+                "index out of bounds: the shape is ({}, {}) but the index is ({}, {}).",
+                self.rows, self.cols, i, j
+            )
+        }
+    }
+}
+
+impl IndexMut<(usize, usize)> for MatrixViewMut<'_> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut f64 {
+        if i < self.rows && j < self.cols {
+            &mut self.data[self.offset + i * self.stride + j]
+        } else {
+            panic!(
+                "index out of bounds: the shape is ({}, {}) but the index is ({}, {}).",
+                self.rows, self.cols, i, j
+            )
+        }
+    }
+}
+
+// View Multiplication
+impl Mul for MatrixView<'_> {
+    type Output = Matrix;
+    fn mul(self, rhs: MatrixView<'_>) -> Self::Output {
+        multiply(self, rhs)
+    }
+}
+
+impl Mul<&Matrix> for MatrixView<'_> {
+    type Output = Matrix;
+    fn mul(self, rhs: &Matrix) -> Self::Output {
+        multiply(self, MatrixView::from(rhs))
+    }
+}
+
+impl Mul<MatrixView<'_>> for &Matrix {
+    type Output = Matrix;
+    fn mul(self, rhs: MatrixView<'_>) -> Self::Output {
+        multiply(MatrixView::from(self), rhs)
+    }
+}
+
+fn multiply(lhs: MatrixView<'_>, rhs: MatrixView<'_>) -> Matrix {
+    if lhs.cols != rhs.rows {
+        panic!(
+            "LHS cols must be same as RHS rows to multiply. LHS: ({},{}), RHS: ({}, {})",
+            lhs.rows, lhs.cols, rhs.rows, rhs.cols
+        );
+    }
+    Matrix::from_fn(lhs.rows, rhs.cols, |i, j| {
+        (0..lhs.cols).map(|k| lhs[(i, k)] * rhs[(k, j)]).sum()
+    })
+}
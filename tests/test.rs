@@ -1,9 +1,17 @@
-use linalg::Matrix;
+use linalg::{BandedMatrix, LinalgError, Matrix, MatrixView, Norm, Vector};
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn approx_eq(lhs: &Matrix, rhs: &Matrix, tol: f64) -> bool {
+        if lhs.shape() != rhs.shape() {
+            return false;
+        }
+        let (rows, cols) = lhs.shape();
+        (0..rows).all(|i| (0..cols).all(|j| (lhs[(i, j)] - rhs[(i, j)]).abs() < tol))
+    }
+
     #[test]
     fn test_eq() {
         let mat1 = Matrix::from_2d_vec(2, 2, vec![vec![1., 1.], vec![1., 1.]]).unwrap();
@@ -33,6 +41,8 @@ mod tests {
 
         let result = mat1.clone() + mat2.clone();
         assert_eq!(result, expected);
+        assert_eq!(mat1.clone() + Matrix::zeros_like(&mat1), mat1);
+        assert_eq!(mat1.clone() + Matrix::ones_like(&mat1), mat2);
     }
 
     #[test]
@@ -96,4 +106,3280 @@ mod tests {
         let result = std::panic::catch_unwind(|| mat.pow(3));
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_add_zeros_is_noop() {
+        let mat = Matrix::from_2d_vec(2, 2, vec![vec![1., 2.], vec![2., 3.]]).unwrap();
+        let result = mat.clone() + Matrix::zeros_like(&mat);
+        assert_eq!(result, mat);
+    }
+
+    #[test]
+    fn test_add_ones() {
+        let expected = Matrix::from_scalar(2, 3, 2.);
+        let result = Matrix::ones(2, 3) + Matrix::ones(2, 3);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_mul_ones() {
+        let mat = Matrix::from_2d_vec(2, 2, vec![vec![1., 2.], vec![3., 4.]]).unwrap();
+        let expected = Matrix::from_2d_vec(2, 3, vec![vec![3., 3., 3.], vec![7., 7., 7.]]).unwrap();
+        let result = mat * Matrix::ones(2, 3);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_mul_zeros() {
+        let mat = Matrix::from_2d_vec(2, 2, vec![vec![1., 2.], vec![3., 4.]]).unwrap();
+        let result = mat.clone() * Matrix::zeros(2, 2);
+        assert_eq!(result, Matrix::zeros_like(&mat));
+    }
+
+    #[test]
+    fn test_zeros_ones_degenerate_shapes() {
+        assert_eq!(Matrix::zeros(1, 0).shape(), (1, 0));
+        assert_eq!(Matrix::ones(0, 3).shape(), (0, 3));
+        assert_eq!(Matrix::zeros(0, 0).shape(), (0, 0));
+        assert_eq!(Matrix::ones_like(&Matrix::zeros(1, 0)).shape(), (1, 0));
+        assert_eq!(Matrix::zeros(1, 0), Matrix::ones(1, 0));
+    }
+
+    #[test]
+    fn test_from_vec_row_major() {
+        let mat = Matrix::from_vec(2, 3, vec![1., 2., 3., 4., 5., 6.]).unwrap();
+        let expected = Matrix::from_2d_vec(2, 3, vec![vec![1., 2., 3.], vec![4., 5., 6.]]).unwrap();
+        assert_eq!(mat, expected);
+        assert_eq!(mat[(0, 2)], 3.);
+        assert_eq!(mat[(1, 0)], 4.);
+    }
+
+    #[test]
+    fn test_from_vec_length_mismatch() {
+        let result = Matrix::from_vec(2, 2, vec![1., 2., 3.]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_into_vec_round_trip() {
+        let data = vec![1., 2., 3., 4., 5., 6.];
+        let mat = Matrix::from_vec(3, 2, data.clone()).unwrap();
+        assert_eq!(mat.into_vec(), data);
+    }
+
+    #[test]
+    fn test_neg() {
+        let mat = Matrix::from_2d_vec(2, 2, vec![vec![1., -2.], vec![0., 3.]]).unwrap();
+        let expected = Matrix::from_2d_vec(2, 2, vec![vec![-1., 2.], vec![0., -3.]]).unwrap();
+        assert_eq!(-mat.clone(), expected);
+    }
+
+    #[test]
+    fn test_double_neg_returns_mat() {
+        let mat = Matrix::from_2d_vec(2, 3, vec![vec![1., -2., 4.], vec![0., 3., -5.]]).unwrap();
+        let result = -(-mat.clone());
+        assert_eq!(result, mat);
+    }
+
+    #[test]
+    fn test_add_assign() {
+        let mat = Matrix::from_2d_vec(2, 2, vec![vec![1., 2.], vec![2., 3.]]).unwrap();
+        let mut acc = Matrix::zeros(2, 2);
+        for _ in 0..3 {
+            acc += mat.clone();
+        }
+        assert_eq!(acc, 3. * mat);
+    }
+
+    #[test]
+    fn test_add_assign_diff_shape_panics() {
+        let result = std::panic::catch_unwind(|| {
+            let mut acc = Matrix::zeros(2, 2);
+            acc += Matrix::zeros(2, 3);
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_from_fn_identity() {
+        for n in 0..5 {
+            let result = Matrix::from_fn(n, n, |i, j| if i == j { 1. } else { 0. });
+            assert_eq!(result, Matrix::identity(n));
+        }
+    }
+
+    #[test]
+    fn test_from_fn_row_major_order() {
+        let mut counter = 0.;
+        let result = Matrix::from_fn(2, 3, |_, _| {
+            counter += 1.;
+            counter
+        });
+        let expected = Matrix::from_2d_vec(2, 3, vec![vec![1., 2., 3.], vec![4., 5., 6.]]).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_from_fn_empty_does_not_call_closure() {
+        let mut calls = 0;
+        let rows_empty = Matrix::from_fn(0, 3, |_, _| {
+            calls += 1;
+            1.
+        });
+        let cols_empty = Matrix::from_fn(3, 0, |_, _| {
+            calls += 1;
+            1.
+        });
+        assert_eq!(calls, 0);
+        assert_eq!(rows_empty.shape(), (0, 3));
+        assert_eq!(cols_empty.shape(), (3, 0));
+    }
+
+    #[test]
+    fn test_add_ref() {
+        let mat1 = Matrix::from_2d_vec(2, 2, vec![vec![1., 2.], vec![2., 3.]]).unwrap();
+        let mat2 = Matrix::from_2d_vec(2, 2, vec![vec![2., 3.], vec![3., 4.]]).unwrap();
+        let expected = Matrix::from_2d_vec(2, 2, vec![vec![3., 5.], vec![5., 7.]]).unwrap();
+
+        let result = &mat1 + &mat2;
+        assert_eq!(result, expected);
+        assert_eq!(&mat1 + &mat2, mat1 + mat2);
+    }
+
+    #[test]
+    fn test_add_ref_diff_shape_panics() {
+        let mat1 = Matrix::zeros(2, 2);
+        let mat2 = Matrix::zeros(3, 2);
+        let result = std::panic::catch_unwind(|| &mat1 + &mat2);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_from_diag() {
+        let mat = Matrix::from_diag(&[1., 2., 3.]);
+        let expected = Matrix::from_2d_vec(
+            3,
+            3,
+            vec![vec![1., 0., 0.], vec![0., 2., 0.], vec![0., 0., 3.]],
+        )
+        .unwrap();
+        assert_eq!(mat, expected);
+        assert_eq!(mat.clone() * Matrix::identity(3), mat);
+        assert_eq!(Matrix::from_diag(&[1., 1., 1.]), Matrix::identity(3));
+    }
+
+    #[test]
+    fn test_from_diag_offset() {
+        let upper = Matrix::from_diag_offset(&[1., 2.], 1);
+        let expected_upper = Matrix::from_2d_vec(
+            3,
+            3,
+            vec![vec![0., 1., 0.], vec![0., 0., 2.], vec![0., 0., 0.]],
+        )
+        .unwrap();
+        assert_eq!(upper, expected_upper);
+
+        let lower = Matrix::from_diag_offset(&[1., 2.], -2);
+        assert_eq!(lower.shape(), (4, 4));
+        assert_eq!(lower[(2, 0)], 1.);
+        assert_eq!(lower[(3, 1)], 2.);
+        assert_eq!(
+            lower * Matrix::ones(4, 1),
+            Matrix::from_vec(4, 1, vec![0., 0., 1., 2.]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_mul_ref() {
+        let mat1 = Matrix::from_2d_vec(2, 2, vec![vec![1., 2.], vec![3., 4.]]).unwrap();
+        let mat2 = Matrix::ones(2, 1);
+        let expected = Matrix::from_2d_vec(2, 1, vec![vec![3.], vec![7.]]).unwrap();
+
+        let result = &mat1 * &mat2;
+        assert_eq!(result, expected);
+        assert_eq!(&mat1 * &mat2, mat1.clone() * mat2.clone());
+    }
+
+    #[test]
+    fn test_mul_ref_reuses_operand() {
+        let mat = Matrix::from_2d_vec(2, 2, vec![vec![1., 2.], vec![3., 4.]]).unwrap();
+        let mut acc = Matrix::identity(2);
+        for _ in 0..3 {
+            acc = &acc * &mat;
+        }
+        assert_eq!(acc, mat.pow(3));
+    }
+
+    #[test]
+    fn test_mul_ref_wrong_shape_panics() {
+        let mat1 = Matrix::zeros(2, 3);
+        let mat2 = Matrix::zeros(2, 3);
+        let result = std::panic::catch_unwind(|| &mat1 * &mat2);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_from_rows() {
+        let mat = Matrix::from_rows(&[&[1., 2., 3.], &[4., 5., 6.]]).unwrap();
+        let expected = Matrix::from_2d_vec(2, 3, vec![vec![1., 2., 3.], vec![4., 5., 6.]]).unwrap();
+        assert_eq!(mat, expected);
+        assert_eq!(Matrix::from_rows(&[&[1., 2., 3.]]).unwrap().shape(), (1, 3));
+    }
+
+    #[test]
+    fn test_from_cols() {
+        let mat = Matrix::from_cols(&[&[1., 4.], &[2., 5.], &[3., 6.]]).unwrap();
+        let expected = Matrix::from_2d_vec(2, 3, vec![vec![1., 2., 3.], vec![4., 5., 6.]]).unwrap();
+        assert_eq!(mat, expected);
+        assert_eq!(Matrix::from_cols(&[&[1., 2., 3.]]).unwrap().shape(), (3, 1));
+    }
+
+    #[test]
+    fn test_from_rows_cols_empty() {
+        assert_eq!(Matrix::from_rows(&[]).unwrap().shape(), (0, 0));
+        assert_eq!(Matrix::from_cols(&[]).unwrap().shape(), (0, 0));
+    }
+
+    #[test]
+    fn test_from_rows_cols_ragged() {
+        assert!(Matrix::from_rows(&[&[1., 2.], &[3.]]).is_err());
+        assert!(Matrix::from_cols(&[&[1., 2.], &[3.]]).is_err());
+    }
+
+    #[test]
+    fn test_from_cols_ragged_error() {
+        let err = Matrix::from_cols(&[&[1., 2.], &[3., 4.], &[5.]]).unwrap_err();
+        assert_eq!(
+            err,
+            LinalgError::InconsistentCol {
+                col: 2,
+                expected: 2,
+                found: 1
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "Inconsistent row length in column 2: expected 2 rows but found 1"
+        );
+    }
+
+    #[test]
+    fn test_determinant_2x2() {
+        let mat = Matrix::from_2d_vec(2, 2, vec![vec![1., 2.], vec![3., 4.]]).unwrap();
+        let result = mat.determinant().unwrap();
+        assert!((result + 2.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_determinant_identity() {
+        for n in 1..6 {
+            assert_eq!(Matrix::identity(n).determinant().unwrap(), 1.);
+        }
+    }
+
+    #[test]
+    fn test_determinant_needs_pivot() {
+        let mat = Matrix::from_2d_vec(
+            3,
+            3,
+            vec![vec![0., 2., 1.], vec![1., 0., 0.], vec![0., 0., 3.]],
+        )
+        .unwrap();
+        let result = mat.determinant().unwrap();
+        assert!((result + 6.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_determinant_singular() {
+        let mat = Matrix::from_2d_vec(2, 2, vec![vec![1., 2.], vec![2., 4.]]).unwrap();
+        assert_eq!(mat.determinant().unwrap(), 0.);
+    }
+
+    #[test]
+    fn test_determinant_not_square() {
+        assert!(Matrix::zeros(2, 3).determinant().is_err());
+    }
+
+    #[cfg(feature = "rand")]
+    mod random {
+        use super::*;
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        #[test]
+        fn test_random_uniform_reproducible() {
+            let mat1 = Matrix::random_uniform(3, 4, -1., 1., &mut StdRng::seed_from_u64(42));
+            let mat2 = Matrix::random_uniform(3, 4, -1., 1., &mut StdRng::seed_from_u64(42));
+            assert_eq!(mat1, mat2);
+        }
+
+        #[test]
+        fn test_random_uniform_bounds_and_shape() {
+            let mut rng = StdRng::seed_from_u64(7);
+            let mat = Matrix::random_uniform(5, 3, 2., 3., &mut rng);
+            assert_eq!(mat.shape(), (5, 3));
+            for i in 0..5 {
+                for j in 0..3 {
+                    assert!(mat[(i, j)] >= 2. && mat[(i, j)] < 3.);
+                }
+            }
+        }
+
+        #[test]
+        fn test_random_standard_normal_reproducible() {
+            let mat1 = Matrix::random_standard_normal(2, 5, &mut StdRng::seed_from_u64(1));
+            let mat2 = Matrix::random_standard_normal(2, 5, &mut StdRng::seed_from_u64(1));
+            assert_eq!(mat1.shape(), (2, 5));
+            assert_eq!(mat1, mat2);
+        }
+
+        #[test]
+        fn test_random_orthogonal() {
+            let mut rng = StdRng::seed_from_u64(3);
+            for n in 1..6 {
+                let q = Matrix::random_orthogonal(n, &mut rng);
+                let qtq = &q.clone().transpose() * &q;
+                assert!(approx_eq(&qtq, &Matrix::identity(n), 1e-10));
+            }
+        }
+
+        #[test]
+        fn test_random_spd() {
+            let mut rng = StdRng::seed_from_u64(5);
+            let n = 4;
+            let spd = Matrix::random_spd(n, &mut rng);
+            assert_eq!(spd, spd.clone().transpose());
+            for k in 1..=n {
+                let minor = Matrix::from_fn(k, k, |i, j| spd[(i, j)]);
+                assert!(minor.determinant().unwrap() > 0.);
+            }
+        }
+
+        #[test]
+        fn test_randomized_svd_decaying_spectrum() {
+            let mut rng = StdRng::seed_from_u64(11);
+            let u = Matrix::random_orthogonal(40, &mut rng)
+                .submatrix(0..40, 0..20)
+                .unwrap();
+            let v = Matrix::random_orthogonal(20, &mut rng);
+            let s: Vec<f64> = (0..20).map(|i| 0.5f64.powi(i)).collect();
+            let us = Matrix::from_fn(40, 20, |i, j| u[(i, j)] * s[j]);
+            let a = &us * &v.transpose();
+
+            let k = 5;
+            let exact = a.svd().unwrap();
+            let approx = a.randomized_svd(k, 10, 2, &mut rng).unwrap();
+            assert_eq!(approx.u.shape(), (40, k));
+            assert_eq!(approx.vt.shape(), (k, 20));
+            for i in 0..k {
+                assert!((approx.s[i] - exact.s[i]).abs() < 1e-6);
+            }
+            let optimal = s[k..].iter().map(|x| x * x).sum::<f64>().sqrt();
+            let error = (a.clone() + approx.reconstruct() * -1.).frobenius_norm();
+            assert!(error < 1.01 * optimal);
+        }
+
+        #[test]
+        fn test_randomized_svd_invalid_rank() {
+            let mut rng = StdRng::seed_from_u64(3);
+            let a = Matrix::random_standard_normal(6, 4, &mut rng);
+            assert!(matches!(
+                a.randomized_svd(0, 2, 0, &mut rng),
+                Err(LinalgError::InvalidArgument(_))
+            ));
+            assert!(matches!(
+                a.randomized_svd(5, 2, 0, &mut rng),
+                Err(LinalgError::InvalidArgument(_))
+            ));
+        }
+
+        #[test]
+        fn test_banded_solve_random() {
+            let mut rng = StdRng::seed_from_u64(5);
+            let n = 40;
+            let (kl, ku) = (3, 2);
+            let a = Matrix::random_uniform(n, n, -1., 1., &mut rng)
+                .tril(ku as i64)
+                .triu(-(kl as i64));
+            let b = Matrix::random_standard_normal(n, 1, &mut rng);
+            let x = a.to_banded(kl, ku, 0.).unwrap().solve(&b).unwrap();
+            assert!(approx_eq(&x, &a.solve(&b).unwrap(), 1e-8));
+        }
+    }
+
+    #[test]
+    fn test_inverse() {
+        let mat = Matrix::from_2d_vec(
+            3,
+            3,
+            vec![vec![0., 2., 1.], vec![1., 1., 0.], vec![3., 0., 4.]],
+        )
+        .unwrap();
+        let inv = mat.inverse().unwrap();
+        assert!(approx_eq(&(&mat * &inv), &Matrix::identity(3), 1e-12));
+        assert!(approx_eq(&(&inv * &mat), &Matrix::identity(3), 1e-12));
+    }
+
+    #[test]
+    fn test_inverse_2x2() {
+        let mat = Matrix::from_2d_vec(2, 2, vec![vec![4., 7.], vec![2., 6.]]).unwrap();
+        let expected = Matrix::from_2d_vec(2, 2, vec![vec![0.6, -0.7], vec![-0.2, 0.4]]).unwrap();
+        assert!(approx_eq(&mat.inverse().unwrap(), &expected, 1e-12));
+    }
+
+    #[test]
+    fn test_inverse_tiny_scale() {
+        let mat = Matrix::from_2d_vec(2, 2, vec![vec![2., 1.], vec![1., 3.]]).unwrap() * 1e-13;
+        let expected =
+            Matrix::from_2d_vec(2, 2, vec![vec![0.6, -0.2], vec![-0.2, 0.4]]).unwrap() * 1e13;
+        assert!(approx_eq(&mat.inverse().unwrap(), &expected, 1e-12 * 1e13));
+    }
+
+    #[test]
+    fn test_inverse_singular() {
+        let mat = Matrix::from_2d_vec(2, 2, vec![vec![1., 2.], vec![2., 4.]]).unwrap();
+        assert!(mat.inverse().is_err());
+    }
+
+    #[test]
+    fn test_inverse_not_square() {
+        assert!(Matrix::ones(2, 3).inverse().is_err());
+    }
+
+    #[test]
+    fn test_qr_reconstructs() {
+        let mat = Matrix::from_2d_vec(
+            4,
+            3,
+            vec![
+                vec![12., -51., 4.],
+                vec![6., 167., -68.],
+                vec![-4., 24., -41.],
+                vec![1., 1., 1.],
+            ],
+        )
+        .unwrap();
+        let (q, r) = mat.qr().unwrap();
+        assert_eq!(q.shape(), (4, 3));
+        assert_eq!(r.shape(), (3, 3));
+        assert!(approx_eq(&(&q * &r), &mat, 1e-10));
+        assert!(approx_eq(
+            &(&q.clone().transpose() * &q),
+            &Matrix::identity(3),
+            1e-12
+        ));
+        assert_eq!(r[(1, 0)], 0.);
+        assert!(Matrix::zeros(2, 3).qr().is_err());
+    }
+
+    #[test]
+    fn test_qr_tiny_scale() {
+        let square = Matrix::from_2d_vec(2, 2, vec![vec![2., 1.], vec![1., 3.]]).unwrap() * 1e-13;
+        let tall = Matrix::from_2d_vec(3, 2, vec![vec![1e-13, 1.], vec![1e-13, 2.], vec![0., 3.]])
+            .unwrap();
+        for mat in [square, tall] {
+            let (q, r) = mat.qr().unwrap();
+            let scale = mat.frobenius_norm();
+            assert!(approx_eq(&(&q * &r), &mat, 1e-14 * scale));
+            assert!(approx_eq(
+                &(&q.clone().transpose() * &q),
+                &Matrix::identity(2),
+                1e-12
+            ));
+        }
+    }
+
+    #[test]
+    fn test_trace() {
+        let mat = Matrix::from_2d_vec(2, 2, vec![vec![1., 2.], vec![3., 4.]]).unwrap();
+        assert_eq!(mat.trace().unwrap(), 5.);
+        for n in 0..5 {
+            assert_eq!(Matrix::identity(n).trace().unwrap(), n as f64);
+        }
+    }
+
+    #[test]
+    fn test_trace_not_square() {
+        assert!(Matrix::ones(2, 3).trace().is_err());
+    }
+
+    #[test]
+    fn test_eye_square_matches_identity() {
+        for n in 0..5 {
+            assert_eq!(Matrix::eye(n, n, 0), Matrix::identity(n));
+        }
+    }
+
+    #[test]
+    fn test_eye_tall_positive_offset() {
+        let mat = Matrix::eye(4, 3, 1);
+        let expected = Matrix::from_2d_vec(
+            4,
+            3,
+            vec![
+                vec![0., 1., 0.],
+                vec![0., 0., 1.],
+                vec![0., 0., 0.],
+                vec![0., 0., 0.],
+            ],
+        )
+        .unwrap();
+        assert_eq!(mat, expected);
+    }
+
+    #[test]
+    fn test_eye_wide_negative_offset() {
+        let mat = Matrix::eye(2, 4, -1);
+        let expected =
+            Matrix::from_2d_vec(2, 4, vec![vec![0., 0., 0., 0.], vec![1., 0., 0., 0.]]).unwrap();
+        assert_eq!(mat, expected);
+    }
+
+    #[test]
+    fn test_eye_offset_out_of_range() {
+        assert_eq!(Matrix::eye(3, 3, 3), Matrix::zeros(3, 3));
+        assert_eq!(Matrix::eye(2, 5, -2), Matrix::zeros(2, 5));
+    }
+
+    #[test]
+    fn test_frobenius_norm() {
+        let mat = Matrix::from_2d_vec(1, 2, vec![vec![3., 4.]]).unwrap();
+        assert_eq!(mat.frobenius_norm(), 5.);
+        assert_eq!(Matrix::identity(4).frobenius_norm(), 2.);
+        assert_eq!(Matrix::zeros(3, 2).frobenius_norm(), 0.);
+    }
+
+    #[test]
+    fn test_linspace() {
+        let result = Matrix::linspace(0., 1., 5);
+        let expected = Matrix::from_vec(1, 5, vec![0., 0.25, 0.5, 0.75, 1.]).unwrap();
+        assert_eq!(result, expected);
+        assert_eq!(Matrix::linspace(2., 3., 1), Matrix::from_scalar(1, 1, 2.));
+        assert_eq!(Matrix::linspace(2., 3., 0).shape(), (1, 0));
+    }
+
+    #[test]
+    fn test_linspace_col() {
+        let result = Matrix::linspace_col(-1., 1., 3);
+        let expected = Matrix::from_vec(3, 1, vec![-1., 0., 1.]).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_arange() {
+        let result = Matrix::arange(0., 1., 0.1).unwrap();
+        assert_eq!(result.shape(), (1, 10));
+        assert!((result[(0, 9)] - 0.9).abs() < 1e-12);
+
+        let result = Matrix::arange(0., 1., 0.3).unwrap();
+        assert_eq!(result.shape(), (1, 4));
+        assert!((result[(0, 3)] - 0.9).abs() < 1e-12);
+
+        let result = Matrix::arange_col(3., 0., -1.5).unwrap();
+        assert_eq!(result, Matrix::from_vec(2, 1, vec![3., 1.5]).unwrap());
+    }
+
+    #[test]
+    fn test_arange_bad_step() {
+        assert!(Matrix::arange(0., 1., 0.).is_err());
+        assert!(Matrix::arange(0., 1., -0.5).is_err());
+        assert!(Matrix::arange_col(1., 0., 0.5).is_err());
+    }
+
+    #[test]
+    fn test_arange_non_finite() {
+        for (start, stop, step) in [
+            (0., f64::INFINITY, 1.),
+            (f64::NEG_INFINITY, 0., 1.),
+            (0., 1., f64::INFINITY),
+            (f64::NAN, 1., 0.5),
+            (0., f64::NAN, 0.5),
+            (0., 1., f64::NAN),
+        ] {
+            assert!(matches!(
+                Matrix::arange(start, stop, step),
+                Err(LinalgError::InvalidArgument(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_norm_one() {
+        let mat = Matrix::from_2d_vec(2, 2, vec![vec![1., -2.], vec![-3., 4.]]).unwrap();
+        assert_eq!(mat.norm(Norm::One), 6.);
+    }
+
+    #[test]
+    fn test_norm_inf() {
+        let mat = Matrix::from_2d_vec(2, 2, vec![vec![1., -2.], vec![-3., 4.]]).unwrap();
+        assert_eq!(mat.norm(Norm::Inf), 7.);
+    }
+
+    #[test]
+    fn test_norm_frobenius() {
+        let mat = Matrix::from_2d_vec(2, 2, vec![vec![1., -2.], vec![-3., 4.]]).unwrap();
+        assert_eq!(mat.norm(Norm::Frobenius), 30f64.sqrt());
+        assert_eq!(Matrix::zeros(0, 0).norm(Norm::One), 0.);
+    }
+
+    #[test]
+    fn test_tridiagonal() {
+        let mat = Matrix::tridiagonal(4, -1., 2., -1.);
+        let expected = Matrix::from_2d_vec(
+            4,
+            4,
+            vec![
+                vec![2., -1., 0., 0.],
+                vec![-1., 2., -1., 0.],
+                vec![0., -1., 2., -1.],
+                vec![0., 0., -1., 2.],
+            ],
+        )
+        .unwrap();
+        assert_eq!(mat, expected);
+    }
+
+    #[test]
+    fn test_from_tridiagonal() {
+        let mat =
+            Matrix::from_tridiagonal(&[1., 2., 3.], &[4., 5., 6., 7.], &[8., 9., 10.]).unwrap();
+        let expected = Matrix::from_2d_vec(
+            4,
+            4,
+            vec![
+                vec![4., 8., 0., 0.],
+                vec![1., 5., 9., 0.],
+                vec![0., 2., 6., 10.],
+                vec![0., 0., 3., 7.],
+            ],
+        )
+        .unwrap();
+        for i in 0..4 {
+            for j in 0..4 {
+                assert_eq!(mat[(i, j)], expected[(i, j)]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_tridiagonal_bad_lengths() {
+        assert!(Matrix::from_tridiagonal(&[1.], &[1., 2., 3.], &[1., 2.]).is_err());
+        assert!(Matrix::from_tridiagonal(&[1., 2.], &[1., 2., 3.], &[1., 2., 3.]).is_err());
+        assert_eq!(
+            Matrix::from_tridiagonal(&[], &[5.], &[]).unwrap(),
+            Matrix::from_scalar(1, 1, 5.)
+        );
+    }
+
+    #[test]
+    fn test_lu_reconstructs() {
+        let mat = Matrix::from_2d_vec(
+            3,
+            3,
+            vec![vec![1., 2., 3.], vec![4., 5., 6.], vec![7., 8., 10.]],
+        )
+        .unwrap();
+        let (p, l, u) = mat.lu().unwrap();
+        assert!(approx_eq(&(&(&p * &l) * &u), &mat, 1e-12));
+        for i in 0..3 {
+            assert_eq!(l[(i, i)], 1.);
+            for j in (i + 1)..3 {
+                assert_eq!(l[(i, j)], 0.);
+                assert_eq!(u[(j, i)], 0.);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lu_singular_reconstructs() {
+        let mat = Matrix::from_2d_vec(2, 2, vec![vec![1., 2.], vec![2., 4.]]).unwrap();
+        let (p, l, u) = mat.lu().unwrap();
+        assert!(approx_eq(&(&(&p * &l) * &u), &mat, 1e-12));
+    }
+
+    #[test]
+    fn test_lu_not_square() {
+        assert!(Matrix::ones(3, 2).lu().is_err());
+    }
+
+    #[test]
+    fn test_toeplitz() {
+        let mat = Matrix::toeplitz(&[1., 2., 3.], &[1., 4., 5., 6.]).unwrap();
+        let expected = Matrix::from_2d_vec(
+            3,
+            4,
+            vec![
+                vec![1., 4., 5., 6.],
+                vec![2., 1., 4., 5.],
+                vec![3., 2., 1., 4.],
+            ],
+        )
+        .unwrap();
+        assert_eq!(mat, expected);
+    }
+
+    #[test]
+    fn test_toeplitz_mismatched_corner() {
+        assert!(Matrix::toeplitz(&[1., 2.], &[3., 4.]).is_err());
+    }
+
+    #[test]
+    fn test_circulant() {
+        let mat = Matrix::circulant(&[1., 2., 3.]);
+        let expected = Matrix::from_2d_vec(
+            3,
+            3,
+            vec![vec![1., 2., 3.], vec![3., 1., 2.], vec![2., 3., 1.]],
+        )
+        .unwrap();
+        assert_eq!(mat, expected);
+    }
+
+    #[test]
+    fn test_circulant_times_ones() {
+        let c = [1., -2., 0.5, 4.];
+        let result = Matrix::circulant(&c) * Matrix::ones(4, 1);
+        assert_eq!(result, Matrix::from_scalar(4, 1, c.iter().sum()));
+    }
+
+    #[test]
+    fn test_solve_3x3() {
+        // 2x + y - z = 8, -3x - y + 2z = -11, -2x + y + 2z = -3  =>  x = 2, y = 3, z = -1
+        let a = Matrix::from_2d_vec(
+            3,
+            3,
+            vec![vec![2., 1., -1.], vec![-3., -1., 2.], vec![-2., 1., 2.]],
+        )
+        .unwrap();
+        let b = Matrix::from_vec(3, 1, vec![8., -11., -3.]).unwrap();
+        let expected = Matrix::from_vec(3, 1, vec![2., 3., -1.]).unwrap();
+        assert!(approx_eq(&a.solve(&b).unwrap(), &expected, 1e-12));
+    }
+
+    #[test]
+    fn test_solve_multiple_rhs() {
+        let a = Matrix::from_2d_vec(2, 2, vec![vec![0., 2.], vec![1., 1.]]).unwrap();
+        let b = Matrix::identity(2);
+        let x = a.solve(&b).unwrap();
+        assert!(approx_eq(&x, &a.inverse().unwrap(), 1e-12));
+    }
+
+    #[test]
+    fn test_solve_errors() {
+        let singular = Matrix::from_2d_vec(2, 2, vec![vec![1., 2.], vec![2., 4.]]).unwrap();
+        assert!(singular.solve(&Matrix::ones(2, 1)).is_err());
+        assert!(Matrix::identity(2).solve(&Matrix::ones(3, 1)).is_err());
+        assert!(Matrix::ones(2, 3).solve(&Matrix::ones(2, 1)).is_err());
+    }
+
+    #[test]
+    fn test_vandermonde_quadratic() {
+        let mat = Matrix::vandermonde(&[1., 2., 3.], 2);
+        let expected = Matrix::from_2d_vec(
+            3,
+            3,
+            vec![vec![1., 1., 1.], vec![1., 2., 4.], vec![1., 3., 9.]],
+        )
+        .unwrap();
+        assert_eq!(mat, expected);
+
+        let decreasing = Matrix::vandermonde_decreasing(&[1., 2., 3.], 2);
+        assert_eq!(decreasing[(2, 0)], 9.);
+        assert_eq!(decreasing[(2, 2)], 1.);
+    }
+
+    #[test]
+    fn test_vandermonde_evaluates_polynomial() {
+        // p(x) = 1 - 2x + 3x^2
+        let x = [-1., 0., 0.5, 2.];
+        let coeffs = Matrix::from_vec(3, 1, vec![1., -2., 3.]).unwrap();
+        let result = Matrix::vandermonde(&x, 2) * coeffs;
+        let expected = Matrix::from_fn(4, 1, |i, _| 1. - 2. * x[i] + 3. * x[i] * x[i]);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_vandermonde_degenerate() {
+        assert_eq!(Matrix::vandermonde(&[2., 3.], 0), Matrix::ones(2, 1));
+        assert_eq!(Matrix::vandermonde(&[], 3).shape(), (0, 4));
+    }
+
+    #[test]
+    fn test_hilbert() {
+        let mat = Matrix::hilbert(3);
+        let expected = Matrix::from_2d_vec(
+            3,
+            3,
+            vec![
+                vec![1., 1. / 2., 1. / 3.],
+                vec![1. / 2., 1. / 3., 1. / 4.],
+                vec![1. / 3., 1. / 4., 1. / 5.],
+            ],
+        )
+        .unwrap();
+        assert_eq!(mat, expected);
+    }
+
+    #[test]
+    fn test_pascal() {
+        let mat = Matrix::pascal(5);
+        assert_eq!(mat, mat.clone().transpose());
+        for i in 0..5 {
+            for j in 0..5 {
+                assert_eq!(mat[(i, j)], mat[(i, j)].round());
+            }
+        }
+        assert_eq!(mat[(4, 4)], 70.);
+        assert_eq!(mat[(2, 3)], 10.);
+    }
+
+    #[test]
+    fn test_mul_vec() {
+        let mat = Matrix::from_2d_vec(2, 3, vec![vec![1., 2., 3.], vec![4., 5., 6.]]).unwrap();
+        assert_eq!(mat.mul_vec(&[1., 0., -1.]).unwrap(), vec![-2., -2.]);
+    }
+
+    #[test]
+    fn test_mul_vec_wrong_length() {
+        let mat = Matrix::ones(2, 3);
+        assert!(mat.mul_vec(&[1., 2.]).is_err());
+    }
+
+    #[test]
+    fn test_vector_dot_orthogonal() {
+        let v1 = Vector::new(vec![1., 2., 0.]);
+        let v2 = Vector::new(vec![-2., 1., 5.]);
+        assert_eq!(v1.dot(&v2), 0.);
+        assert_eq!(v1.dot(&v1), 5.);
+    }
+
+    #[test]
+    fn test_vector_norm_and_scale() {
+        let v = Vector::new(vec![3., 4.]);
+        assert_eq!(v.norm(), 5.);
+        assert_eq!(2. * v.clone(), Vector::new(vec![6., 8.]));
+        assert_eq!(v * -1., Vector::new(vec![-3., -4.]));
+    }
+
+    #[test]
+    fn test_vector_matrix_conversion() {
+        let v = Vector::new(vec![1., 2., 3.]);
+        let mat = Matrix::from(v.clone());
+        assert_eq!(mat, Matrix::from_vec(3, 1, vec![1., 2., 3.]).unwrap());
+        assert_eq!(Vector::try_from(mat).unwrap(), v);
+        assert!(Vector::try_from(Matrix::ones(2, 2)).is_err());
+    }
+
+    #[test]
+    fn test_matrix_vector_product() {
+        let mat = Matrix::from_2d_vec(2, 3, vec![vec![1., 2., 3.], vec![4., 5., 6.]]).unwrap();
+        let v = Vector::new(vec![1., 0., -1.]);
+        assert_eq!(mat * v, Vector::new(vec![-2., -2.]));
+    }
+
+    #[test]
+    fn test_from_blocks() {
+        let a = Matrix::from_scalar(2, 3, 1.);
+        let b = Matrix::from_scalar(2, 1, 2.);
+        let c = Matrix::from_scalar(1, 3, 3.);
+        let d = Matrix::from_scalar(1, 1, 4.);
+        let mat = Matrix::from_blocks(vec![vec![a, b], vec![c, d]]).unwrap();
+        assert_eq!(mat.shape(), (3, 4));
+        assert_eq!(mat[(0, 0)], 1.);
+        assert_eq!(mat[(1, 2)], 1.);
+        assert_eq!(mat[(1, 3)], 2.);
+        assert_eq!(mat[(2, 2)], 3.);
+        assert_eq!(mat[(2, 3)], 4.);
+    }
+
+    #[test]
+    fn test_from_blocks_inconsistent_heights() {
+        let a = Matrix::zeros(2, 2);
+        let b = Matrix::zeros(3, 2);
+        let err = Matrix::from_blocks(vec![vec![a, b]]).unwrap_err();
+        assert!(err.to_string().contains("(0, 1)"));
+    }
+
+    #[test]
+    fn test_from_blocks_inconsistent_widths() {
+        let err = Matrix::from_blocks(vec![
+            vec![Matrix::zeros(1, 2), Matrix::zeros(1, 2)],
+            vec![Matrix::zeros(1, 2), Matrix::zeros(1, 3)],
+        ])
+        .unwrap_err();
+        assert!(err.to_string().contains("(1, 1)"));
+    }
+
+    #[test]
+    fn test_block_diag() {
+        let row = Matrix::from_vec(1, 3, vec![5., 6., 7.]).unwrap();
+        let mat = Matrix::block_diag(&[Matrix::identity(2), row]);
+        let expected = Matrix::from_2d_vec(
+            3,
+            5,
+            vec![
+                vec![1., 0., 0., 0., 0.],
+                vec![0., 1., 0., 0., 0.],
+                vec![0., 0., 5., 6., 7.],
+            ],
+        )
+        .unwrap();
+        assert_eq!(mat, expected);
+    }
+
+    #[test]
+    fn test_block_diag_single_and_empty() {
+        let mat = Matrix::from_2d_vec(2, 3, vec![vec![1., 2., 3.], vec![4., 5., 6.]]).unwrap();
+        assert_eq!(Matrix::block_diag(std::slice::from_ref(&mat)), mat);
+        assert_eq!(Matrix::block_diag(&[]).shape(), (0, 0));
+    }
+
+    #[test]
+    fn test_kron_identity() {
+        let mat = Matrix::from_2d_vec(2, 2, vec![vec![1., 2.], vec![3., 4.]]).unwrap();
+        let result = Matrix::identity(2).kron(&mat);
+        assert_eq!(result, Matrix::block_diag(&[mat.clone(), mat]));
+    }
+
+    #[test]
+    fn test_kron_rectangular() {
+        let a = Matrix::from_vec(1, 2, vec![1., -1.]).unwrap();
+        let b = Matrix::from_vec(2, 1, vec![2., 3.]).unwrap();
+        let expected = Matrix::from_2d_vec(2, 2, vec![vec![2., -2.], vec![3., -3.]]).unwrap();
+        assert_eq!(a.kron(&b), expected);
+        assert_eq!(a.kron(&Matrix::zeros(0, 3)).shape(), (0, 6));
+    }
+
+    #[test]
+    fn test_diagonal() {
+        assert_eq!(Matrix::identity(4).diagonal(), vec![1.0; 4]);
+        let mat = Matrix::from_2d_vec(2, 3, vec![vec![1., 2., 3.], vec![4., 5., 6.]]).unwrap();
+        assert_eq!(mat.diagonal(), vec![1., 5.]);
+        assert_eq!(mat.transpose().diagonal(), vec![1., 5.]);
+    }
+
+    #[test]
+    fn test_from_diagonal() {
+        assert_eq!(Matrix::from_diagonal(&[1.0, 1.0, 1.0]), Matrix::identity(3));
+        let values = [2., -1., 4.];
+        assert_eq!(Matrix::from_diagonal(&values).diagonal(), values.to_vec());
+    }
+
+    #[test]
+    fn test_zeros_ones_values() {
+        let zeros = Matrix::zeros(2, 3);
+        let ones = Matrix::ones(3, 2);
+        assert_eq!(zeros.shape(), (2, 3));
+        assert_eq!(ones.shape(), (3, 2));
+        assert_eq!(zeros[(1, 2)], 0.);
+        assert_eq!(ones[(2, 1)], 1.);
+        assert_eq!(zeros, Matrix::from_scalar(2, 3, 0.));
+        assert_eq!(ones, Matrix::from_scalar(3, 2, 1.));
+    }
+
+    #[test]
+    fn test_givens_coeffs_zeroes_second_entry() {
+        for &(a, b) in &[(3., 4.), (-1., 2.), (0., -5.), (2., 0.), (0., 0.)] {
+            let (c, s, r) = Matrix::givens_coeffs(a, b);
+            let v = Matrix::from_vec(2, 1, vec![a, b]).unwrap();
+            let rotated = Matrix::givens(2, 0, 1, c, s) * v;
+            assert!((rotated[(0, 0)] - r).abs() < 1e-12);
+            assert!(rotated[(1, 0)].abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_apply_givens_matches_multiplication() {
+        let mat = Matrix::from_fn(5, 5, |i, j| ((3 * i + 7 * j) as f64).sin());
+        let (c, s, _) = Matrix::givens_coeffs(mat[(1, 0)], mat[(3, 0)]);
+
+        let mut left = mat.clone();
+        left.apply_givens_left(1, 3, c, s);
+        assert!(approx_eq(
+            &left,
+            &(&Matrix::givens(5, 1, 3, c, s) * &mat),
+            1e-12
+        ));
+        assert!(left[(3, 0)].abs() < 1e-12);
+
+        let mut right = mat.clone();
+        right.apply_givens_right(0, 4, c, s);
+        assert!(approx_eq(
+            &right,
+            &(&mat * &Matrix::givens(5, 0, 4, c, s)),
+            1e-12
+        ));
+    }
+
+    #[test]
+    fn test_givens_bad_indices_panic() {
+        assert!(std::panic::catch_unwind(|| Matrix::givens(3, 1, 1, 1., 0.)).is_err());
+        assert!(std::panic::catch_unwind(|| Matrix::givens(3, 0, 3, 1., 0.)).is_err());
+    }
+
+    #[test]
+    fn test_from_fn_flat_index() {
+        let cols = 4;
+        let mat = Matrix::from_fn(3, cols, |i, j| (i * cols + j) as f64);
+        assert_eq!(mat[(0, 0)], 0.);
+        assert_eq!(mat[(1, 2)], 6.);
+        assert_eq!(mat[(2, 3)], 11.);
+        assert_eq!(
+            mat,
+            Matrix::from_vec(3, 4, (0..12).map(|x| x as f64).collect()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_permutation_matches_permute_rows() {
+        let mat = Matrix::from_fn(3, 2, |i, j| (10 * i + j) as f64);
+        let perm = [2, 0, 1];
+        let p = Matrix::permutation(&perm).unwrap();
+        assert_eq!(&p * &mat, mat.permute_rows(&perm));
+        assert_eq!(
+            &mat.clone().transpose() * &p.transpose(),
+            mat.clone().transpose().permute_cols(&perm)
+        );
+    }
+
+    #[test]
+    fn test_permute_inverse_recovers_original() {
+        let mat = Matrix::from_fn(4, 3, |i, j| (i * 3 + j) as f64);
+        let perm = [3, 1, 0, 2];
+        let mut inverse = [0; 4];
+        for (k, &p) in perm.iter().enumerate() {
+            inverse[p] = k;
+        }
+        assert_eq!(mat.permute_rows(&perm).permute_rows(&inverse), mat);
+        assert_eq!(mat.permute_cols(&[2, 0, 1]).permute_cols(&[1, 2, 0]), mat);
+    }
+
+    #[test]
+    fn test_permutation_invalid() {
+        let repeated = Matrix::permutation(&[0, 1, 1]).unwrap_err();
+        assert!(repeated.to_string().contains("position 2"));
+        let out_of_range = Matrix::permutation(&[0, 3, 1]).unwrap_err();
+        assert_eq!(
+            out_of_range,
+            LinalgError::IndexOutOfBounds { index: 3, bound: 3 }
+        );
+        let mat = Matrix::ones(2, 2);
+        assert!(std::panic::catch_unwind(|| mat.permute_rows(&[0, 0])).is_err());
+    }
+
+    #[test]
+    fn test_map() {
+        let mat = Matrix::from_2d_vec(2, 3, vec![vec![1., -2., 3.], vec![0., 5., -6.]]).unwrap();
+        let result = mat.map(|x| x * 2.0);
+        assert_eq!(result, 2. * mat.clone());
+        assert_eq!(mat.map(f64::abs)[(1, 2)], 6.);
+    }
+
+    #[test]
+    fn test_tile() {
+        let mat = Matrix::from_2d_vec(2, 3, vec![vec![1., 2., 3.], vec![4., 5., 6.]]).unwrap();
+        let tiled = mat.tile(2, 2);
+        assert_eq!(tiled.shape(), (4, 6));
+        assert_eq!(tiled[(0, 2)], 3.);
+        assert_eq!(tiled[(0, 3)], 1.);
+        assert_eq!(tiled[(1, 5)], 6.);
+        assert_eq!(tiled[(2, 0)], 1.);
+        assert_eq!(tiled[(3, 3)], 4.);
+        assert_eq!(mat.tile(1, 1), mat);
+        assert_eq!(mat.tile(0, 2).shape(), (0, 6));
+        assert_eq!(mat.tile(3, 0).shape(), (6, 0));
+    }
+
+    #[test]
+    fn test_repeat_elements() {
+        let mat = Matrix::from_2d_vec(2, 3, vec![vec![1., 2., 3.], vec![4., 5., 6.]]).unwrap();
+        let repeated = mat.repeat_elements(2, 2);
+        assert_eq!(repeated, mat.kron(&Matrix::ones(2, 2)));
+        assert_eq!(repeated[(1, 1)], 1.);
+        assert_eq!(repeated[(1, 2)], 2.);
+        assert_eq!(repeated[(2, 1)], 4.);
+        assert_eq!(mat.repeat_elements(0, 1).shape(), (0, 3));
+    }
+
+    #[test]
+    fn test_from_2d_vec_bad_row_count() {
+        let err = Matrix::from_2d_vec(3, 2, vec![vec![1., 2.], vec![3., 4.]]).unwrap_err();
+        assert_eq!(
+            err,
+            LinalgError::ShapeMismatch {
+                expected: (3, 2),
+                found: (2, 2)
+            }
+        );
+        let err = Matrix::from_2d_vec(3, 2, vec![vec![1., 2., 3.]]).unwrap_err();
+        assert_eq!(
+            err,
+            LinalgError::ShapeMismatch {
+                expected: (3, 2),
+                found: (1, 3)
+            }
+        );
+        assert_eq!(
+            Matrix::from_2d_vec(1, 2, vec![]),
+            Err(LinalgError::ShapeMismatch {
+                expected: (1, 2),
+                found: (0, 0)
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_2d_vec_names_bad_row() {
+        let err =
+            Matrix::from_2d_vec(3, 2, vec![vec![1., 2.], vec![3., 4.], vec![5.]]).unwrap_err();
+        assert!(err.to_string().contains("row 2"));
+        assert!(err.to_string().contains("expected 2 columns but found 1"));
+    }
+
+    #[test]
+    fn test_from_rows_names_bad_row() {
+        let err = Matrix::from_rows(&[&[1., 2.], &[3., 4., 5.]]).unwrap_err();
+        assert!(err.to_string().contains("row 1"));
+    }
+
+    #[test]
+    fn test_get() {
+        let mat = Matrix::from_2d_vec(2, 3, vec![vec![1., 2., 3.], vec![4., 5., 6.]]).unwrap();
+        assert_eq!(mat.get(1, 2), Some(&6.));
+        assert_eq!(mat.get(2, 0), None);
+        assert_eq!(mat.get(0, 3), None);
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut mat = Matrix::zeros(2, 2);
+        *mat.get_mut(0, 1).unwrap() = 7.;
+        assert_eq!(mat[(0, 1)], 7.);
+        assert!(mat.get_mut(2, 2).is_none());
+    }
+
+    #[test]
+    fn test_error_variants() {
+        assert_eq!(
+            Matrix::ones(2, 3).determinant(),
+            Err(LinalgError::NotSquare)
+        );
+        let singular = Matrix::from_2d_vec(2, 2, vec![vec![1., 2.], vec![2., 4.]]).unwrap();
+        assert_eq!(singular.inverse(), Err(LinalgError::Singular));
+        assert_eq!(
+            Matrix::from_2d_vec(2, 2, vec![vec![1., 2.], vec![3.]]),
+            Err(LinalgError::InconsistentRow {
+                row: 1,
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            Matrix::identity(2).solve(&Matrix::ones(3, 1)),
+            Err(LinalgError::ShapeMismatch {
+                expected: (2, 1),
+                found: (3, 1)
+            })
+        );
+    }
+
+    #[test]
+    fn test_error_display() {
+        let err = LinalgError::ShapeMismatch {
+            expected: (2, 2),
+            found: (3, 1),
+        };
+        assert_eq!(
+            err.to_string(),
+            "Shape mismatch: expected (2, 2) but found (3, 1)"
+        );
+        let boxed: Box<dyn std::error::Error> = Box::new(LinalgError::Singular);
+        assert_eq!(boxed.to_string(), "Matrix is singular");
+    }
+
+    #[test]
+    fn test_get_boundaries() {
+        let mat = Matrix::from_fn(3, 4, |i, j| (i * 4 + j) as f64);
+        assert_eq!(mat.get(0, 0), Some(&0.));
+        assert_eq!(mat.get(2, 3), Some(&11.));
+        assert_eq!(mat.get(1, 2), Some(&mat[(1, 2)]));
+        assert_eq!(mat.get(3, 3), None);
+        assert_eq!(mat.get(2, 4), None);
+    }
+
+    #[test]
+    fn test_set() {
+        let mut mat = Matrix::zeros(3, 4);
+        mat.set(0, 0, 1.).unwrap();
+        mat.set(2, 3, 2.).unwrap();
+        assert_eq!(mat[(0, 0)], 1.);
+        assert_eq!(mat[(2, 3)], 2.);
+        assert_eq!(
+            mat.set(3, 0, 5.),
+            Err(LinalgError::IndexOutOfBounds { index: 3, bound: 3 })
+        );
+        assert_eq!(
+            mat.set(0, 4, 5.),
+            Err(LinalgError::IndexOutOfBounds { index: 4, bound: 4 })
+        );
+        assert_eq!(mat.into_vec().iter().sum::<f64>(), 3.);
+    }
+
+    #[test]
+    fn test_checked_add() {
+        let a = Matrix::from_rows(&[&[1., 2.], &[3., 4.]]).unwrap();
+        let b = Matrix::ones(2, 2);
+        let sum = a.checked_add(&b).unwrap();
+        assert_eq!(sum, Matrix::from_rows(&[&[2., 3.], &[4., 5.]]).unwrap());
+        assert_eq!(a[(1, 1)], 4.);
+        assert_eq!(b, Matrix::ones(2, 2));
+    }
+
+    #[test]
+    fn test_checked_add_shape_mismatch() {
+        let a = Matrix::zeros(2, 3);
+        let b = Matrix::zeros(3, 2);
+        assert_eq!(
+            a.checked_add(&b),
+            Err(LinalgError::ShapeMismatch {
+                expected: (2, 3),
+                found: (3, 2)
+            })
+        );
+    }
+
+    #[test]
+    fn test_unchecked_paths_match_checked() {
+        // Deterministic pseudo-random entries in [-1, 1).
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 11) as f64 / (1u64 << 52) as f64 - 1.
+        };
+        for &(m, k, n) in &[(1, 1, 1), (3, 5, 4), (7, 2, 6), (8, 8, 8)] {
+            let a = Matrix::from_fn(m, k, |_, _| next());
+            let b = Matrix::from_fn(k, n, |_, _| next());
+            let expected = Matrix::from_fn(m, n, |i, j| {
+                let mut el = 0.;
+                for p in 0..k {
+                    el += a[(i, p)] * b[(p, j)];
+                }
+                el
+            });
+            assert_eq!(&a * &b, expected);
+            let mut c = a.clone();
+            c *= b.clone();
+            assert_eq!(c, expected);
+            let t = a.clone().transpose();
+            assert_eq!(t.shape(), (k, m));
+            for i in 0..m {
+                for j in 0..k {
+                    assert_eq!(t[(j, i)], a[(i, j)]);
+                    assert_eq!(unsafe { a.get_unchecked(i, j) }, a[(i, j)]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_get_unchecked_mut() {
+        let mut mat = Matrix::zeros(2, 3);
+        unsafe {
+            *mat.get_unchecked_mut(1, 2) = 5.;
+        }
+        assert_eq!(mat[(1, 2)], 5.);
+    }
+
+    #[test]
+    fn test_checked_mul() {
+        let a = Matrix::from_rows(&[&[1., 2., 3.], &[4., 5., 6.]]).unwrap();
+        let b = Matrix::from_rows(&[&[1.], &[0.], &[-1.]]).unwrap();
+        let prod = a.checked_mul(&b).unwrap();
+        assert_eq!(prod, Matrix::from_rows(&[&[-2.], &[-2.]]).unwrap());
+        assert_eq!(prod, &a * &b);
+        assert_eq!(a.shape(), (2, 3));
+        assert_eq!(b.shape(), (3, 1));
+    }
+
+    #[test]
+    fn test_checked_mul_mismatch() {
+        let a = Matrix::zeros(2, 3);
+        let b = Matrix::zeros(2, 3);
+        assert_eq!(
+            a.checked_mul(&b),
+            Err(LinalgError::ShapeMismatch {
+                expected: (3, 3),
+                found: (2, 3)
+            })
+        );
+    }
+
+    #[test]
+    fn test_row_and_col() {
+        let mat = Matrix::from_fn(3, 2, |i, j| (i * 2 + j) as f64);
+        assert_eq!(mat.row(0), &[0., 1.]);
+        assert_eq!(mat.row(2), &[4., 5.]);
+        assert_eq!(mat.col(1), vec![1., 3., 5.]);
+    }
+
+    #[test]
+    fn test_row_mut_aliases_storage() {
+        let mut mat = Matrix::zeros(2, 3);
+        mat.row_mut(1)[2] = 7.;
+        assert_eq!(mat[(1, 2)], 7.);
+        mat[(1, 0)] = -1.;
+        assert_eq!(mat.row(1), &[-1., 0., 7.]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the bound is 2 but the index is 2")]
+    fn test_row_out_of_bounds() {
+        Matrix::zeros(2, 3).row(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the bound is 3 but the index is 5")]
+    fn test_col_out_of_bounds() {
+        Matrix::zeros(2, 3).col(5);
+    }
+
+    #[test]
+    fn test_set_row_and_col() {
+        let mut mat = Matrix::zeros(2, 3);
+        mat.set_row(0, &[1., 2., 3.]).unwrap();
+        mat.set_col(2, &[9., 8.]).unwrap();
+        assert_eq!(
+            mat,
+            Matrix::from_rows(&[&[1., 2., 9.], &[0., 0., 8.]]).unwrap()
+        );
+        assert_eq!(
+            mat.set_row(0, &[1., 2.]),
+            Err(LinalgError::LengthMismatch {
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            mat.set_col(3, &[1., 2.]),
+            Err(LinalgError::IndexOutOfBounds { index: 3, bound: 3 })
+        );
+    }
+
+    #[test]
+    fn test_hstack() {
+        let a = Matrix::from_rows(&[&[1., 2.], &[3., 4.]]).unwrap();
+        let b = Matrix::from_rows(&[&[5.], &[6.]]).unwrap();
+        let stacked = a.hstack(&b).unwrap();
+        assert_eq!(stacked.shape(), (2, 3));
+        assert_eq!(
+            stacked,
+            Matrix::from_rows(&[&[1., 2., 5.], &[3., 4., 6.]]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_hstack_row_mismatch() {
+        let a = Matrix::zeros(2, 2);
+        let b = Matrix::zeros(3, 1);
+        assert_eq!(
+            a.hstack(&b),
+            Err(LinalgError::ShapeMismatch {
+                expected: (2, 1),
+                found: (3, 1)
+            })
+        );
+    }
+
+    #[test]
+    fn test_submatrix_interior() {
+        let mat = Matrix::from_fn(4, 5, |i, j| (i * 5 + j) as f64);
+        let sub = mat.submatrix(1..3, 2..4).unwrap();
+        assert_eq!(sub, Matrix::from_rows(&[&[7., 8.], &[12., 13.]]).unwrap());
+    }
+
+    #[test]
+    fn test_submatrix_touching_last_row_and_col() {
+        let mat = Matrix::from_fn(4, 5, |i, j| (i * 5 + j) as f64);
+        let sub = mat.submatrix(2..4, 3..5).unwrap();
+        assert_eq!(sub, Matrix::from_rows(&[&[13., 14.], &[18., 19.]]).unwrap());
+        assert_eq!(mat.submatrix(0..4, 0..5).unwrap(), mat);
+    }
+
+    #[test]
+    fn test_submatrix_empty_range() {
+        let mat = Matrix::ones(3, 3);
+        assert_eq!(mat.submatrix(1..1, 0..3).unwrap().shape(), (0, 3));
+        assert_eq!(mat.submatrix(0..2, 3..3).unwrap().shape(), (2, 0));
+    }
+
+    #[test]
+    fn test_submatrix_out_of_range() {
+        let mat = Matrix::ones(3, 4);
+        let err = mat.submatrix(1..4, 0..2).unwrap_err();
+        assert_eq!(err.to_string(), "Row range 1..4 is not within 0..3");
+        let err = mat.submatrix(0..2, 2..5).unwrap_err();
+        assert_eq!(err.to_string(), "Column range 2..5 is not within 0..4");
+    }
+
+    #[test]
+    fn test_view_indexes_parent() {
+        let mat = Matrix::from_fn(6, 6, |i, j| (i * 6 + j) as f64);
+        let view = mat.view(2..5, 1..4);
+        assert_eq!(view.shape(), (3, 3));
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_eq!(view[(i, j)], mat[(i + 2, j + 1)]);
+            }
+        }
+        assert_eq!(view.to_matrix(), mat.submatrix(2..5, 1..4).unwrap());
+        let inner = view.view(1..3, 2..3);
+        assert_eq!(inner.shape(), (2, 1));
+        assert_eq!(inner[(1, 0)], mat[(4, 3)]);
+        assert_eq!(MatrixView::from(&mat).to_matrix(), mat);
+    }
+
+    #[test]
+    #[should_panic(expected = "Column range 4..7 is not within 0..6")]
+    fn test_view_out_of_range() {
+        Matrix::zeros(6, 6).view(0..2, 4..7);
+    }
+
+    #[test]
+    fn test_view_multiplication() {
+        let mat = Matrix::from_fn(6, 6, |i, j| ((i * 7 + j * 3) % 5) as f64 - 2.);
+        let a = mat.view(0..3, 1..5);
+        let b = mat.view(2..6, 3..5);
+        let expected = &a.to_matrix() * &b.to_matrix();
+        assert_eq!(a * b, expected);
+        assert_eq!(a * &b.to_matrix(), expected);
+        assert_eq!(&a.to_matrix() * b, expected);
+    }
+
+    #[test]
+    fn test_vstack() {
+        let a = Matrix::from_rows(&[&[1., 2., 3.]]).unwrap();
+        let b = Matrix::from_rows(&[&[4., 5., 6.], &[7., 8., 9.]]).unwrap();
+        let stacked = a.vstack(&b).unwrap();
+        assert_eq!(stacked.shape(), (3, 3));
+        assert_eq!(
+            stacked,
+            Matrix::from_fn(3, 3, |i, j| (i * 3 + j + 1) as f64)
+        );
+    }
+
+    #[test]
+    fn test_vstack_col_mismatch() {
+        let a = Matrix::zeros(1, 3);
+        let b = Matrix::zeros(2, 2);
+        assert_eq!(
+            a.vstack(&b),
+            Err(LinalgError::ShapeMismatch {
+                expected: (2, 3),
+                found: (2, 2)
+            })
+        );
+    }
+
+    #[test]
+    fn test_submatrix_center_of_4x4() {
+        let mat = Matrix::from_fn(4, 4, |i, j| (i * 4 + j) as f64);
+        assert_eq!(
+            mat.submatrix(1..3, 1..3).unwrap(),
+            Matrix::from_rows(&[&[5., 6.], &[9., 10.]]).unwrap()
+        );
+        assert!(mat.submatrix(3..5, 0..1).is_err());
+    }
+
+    #[test]
+    fn test_view_mut_writes_parent() {
+        let mut mat = Matrix::zeros(4, 4);
+        {
+            let mut view = mat.view_mut(1..3, 2..4);
+            assert_eq!(view.shape(), (2, 2));
+            view[(0, 0)] = 1.;
+            view[(1, 1)] = 2.;
+        }
+        assert_eq!(mat[(1, 2)], 1.);
+        assert_eq!(mat[(2, 3)], 2.);
+        mat.view_mut(0..1, 0..4).fill(-1.);
+        assert_eq!(mat.row(0), &[-1., -1., -1., -1.]);
+        assert_eq!(mat.into_vec().iter().filter(|&&x| x != 0.).count(), 6);
+    }
+
+    #[test]
+    fn test_view_mut_copy_and_add_from() {
+        let mut mat = Matrix::zeros(3, 3);
+        let src = Matrix::from_rows(&[&[1., 2.], &[3., 4.]]).unwrap();
+        mat.view_mut(1..3, 0..2).copy_from(&src).unwrap();
+        mat.view_mut(0..2, 1..3)
+            .add_assign_from(src.view(0..2, 0..2))
+            .unwrap();
+        assert_eq!(
+            mat,
+            Matrix::from_rows(&[&[0., 1., 2.], &[1., 5., 4.], &[3., 4., 0.]]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_view_mut_copy_from_shape_mismatch() {
+        let mut mat = Matrix::zeros(3, 3);
+        let src = Matrix::ones(2, 3);
+        assert_eq!(
+            mat.view_mut(0..2, 0..2).copy_from(&src),
+            Err(LinalgError::ShapeMismatch {
+                expected: (2, 2),
+                found: (2, 3)
+            })
+        );
+        assert_eq!(mat, Matrix::zeros(3, 3));
+    }
+
+    #[test]
+    fn test_split_rows_mut() {
+        let mut mat = Matrix::zeros(5, 4);
+        let (mut top, mut bottom) = mat.view_mut(1..5, 1..3).split_rows_mut(1);
+        assert_eq!(top.shape(), (1, 2));
+        assert_eq!(bottom.shape(), (3, 2));
+        top.fill(1.);
+        bottom.fill(2.);
+        bottom[(2, 1)] = top[(0, 0)] + 5.;
+        let (empty, all) = mat.view_mut(0..5, 0..4).split_rows_mut(0);
+        assert_eq!(empty.shape(), (0, 4));
+        assert_eq!(all.shape(), (5, 4));
+        let (all, empty) = mat.view_mut(2..5, 3..4).split_rows_mut(3);
+        assert_eq!((all.shape(), empty.shape()), ((3, 1), (0, 1)));
+        assert_eq!(mat.row(1), &[0., 1., 1., 0.]);
+        assert_eq!(mat.row(2), &[0., 2., 2., 0.]);
+        assert_eq!(mat.row(4), &[0., 2., 6., 0.]);
+        assert_eq!(mat.row(0), &[0., 0., 0., 0.]);
+    }
+
+    #[test]
+    fn test_rows_iter() {
+        let mat = Matrix::from_rows(&[&[1., 2., 3.], &[4., 5., 6.]]).unwrap();
+        let sums: Vec<f64> = mat.rows_iter().map(|row| row.iter().sum()).collect();
+        assert_eq!(sums, vec![6., 15.]);
+        assert_eq!(Matrix::zeros(3, 0).rows_iter().count(), 3);
+        assert_eq!(Matrix::zeros(0, 3).rows_iter().count(), 0);
+    }
+
+    #[test]
+    fn test_swap_rows_and_cols() {
+        let orig = Matrix::from_fn(3, 4, |i, j| (i * 4 + j) as f64);
+        let mut mat = orig.clone();
+        mat.swap_rows(0, 2);
+        assert_eq!(mat.row(0), orig.row(2));
+        assert_eq!(mat.row(2), orig.row(0));
+        assert_eq!(mat.row(1), orig.row(1));
+        mat.swap_rows(2, 0);
+        assert_eq!(mat, orig);
+        mat.swap_cols(1, 3);
+        assert_eq!(mat.col(1), orig.col(3));
+        assert_eq!(mat.col(3), orig.col(1));
+        mat.swap_cols(3, 1);
+        mat.swap_rows(1, 1);
+        mat.swap_cols(2, 2);
+        assert_eq!(mat, orig);
+    }
+
+    #[test]
+    fn test_swap_in_vector_shapes() {
+        let mut row = Matrix::from_rows(&[&[1., 2., 3.]]).unwrap();
+        row.swap_cols(0, 2);
+        row.swap_rows(0, 0);
+        assert_eq!(row.row(0), &[3., 2., 1.]);
+        let mut col = Matrix::from_cols(&[&[1., 2., 3.]]).unwrap();
+        col.swap_rows(0, 1);
+        col.swap_cols(0, 0);
+        assert_eq!(col.col(0), vec![2., 1., 3.]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "index out of bounds: the shape is (2, 2) but the row indices are (0, 2)."
+    )]
+    fn test_swap_rows_out_of_bounds() {
+        Matrix::zeros(2, 2).swap_rows(0, 2);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "index out of bounds: the shape is (2, 2) but the column indices are (3, 1)."
+    )]
+    fn test_swap_cols_out_of_bounds() {
+        Matrix::zeros(2, 2).swap_cols(3, 1);
+    }
+
+    #[test]
+    fn test_insert_row() {
+        let mut mat = Matrix::zeros(0, 0);
+        mat.insert_row(0, &[1., 2.]).unwrap();
+        assert_eq!(mat.shape(), (1, 2));
+        mat.insert_row(1, &[5., 6.]).unwrap();
+        mat.insert_row(1, &[3., 4.]).unwrap();
+        assert_eq!(mat, Matrix::from_fn(3, 2, |i, j| (i * 2 + j + 1) as f64));
+        assert_eq!(
+            mat.insert_row(4, &[0., 0.]),
+            Err(LinalgError::IndexOutOfBounds { index: 4, bound: 4 })
+        );
+        assert_eq!(
+            mat.insert_row(0, &[0.]),
+            Err(LinalgError::LengthMismatch {
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(mat.shape(), (3, 2));
+    }
+
+    #[test]
+    fn test_insert_col() {
+        let mut mat = Matrix::zeros(0, 0);
+        mat.insert_col(0, &[1., 4.]).unwrap();
+        mat.insert_col(1, &[3., 6.]).unwrap();
+        mat.insert_col(1, &[2., 5.]).unwrap();
+        assert_eq!(mat, Matrix::from_fn(2, 3, |i, j| (i * 3 + j + 1) as f64));
+        assert!(mat.insert_col(0, &[1., 2., 3.]).is_err());
+        assert!(mat.insert_col(5, &[1., 2.]).is_err());
+    }
+
+    #[test]
+    fn test_insert_into_empty_keeps_other_dimension() {
+        let mut no_rows = Matrix::zeros(0, 3);
+        assert_eq!(
+            no_rows.insert_row(0, &[1., 2.]),
+            Err(LinalgError::LengthMismatch {
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(no_rows.shape(), (0, 3));
+        no_rows.insert_row(0, &[1., 2., 3.]).unwrap();
+        assert_eq!(no_rows.shape(), (1, 3));
+
+        let mut no_cols = Matrix::zeros(2, 0);
+        assert_eq!(
+            no_cols.insert_col(0, &[1., 2., 3.]),
+            Err(LinalgError::LengthMismatch {
+                expected: 2,
+                found: 3
+            })
+        );
+        assert_eq!(no_cols.shape(), (2, 0));
+        no_cols.insert_col(0, &[1., 2.]).unwrap();
+        assert_eq!(no_cols, Matrix::from_vec(2, 1, vec![1., 2.]).unwrap());
+    }
+
+    #[test]
+    fn test_remove_only_row() {
+        let mut mat = Matrix::from_rows(&[&[1., 2., 3.]]).unwrap();
+        assert_eq!(mat.remove_row(0).unwrap(), vec![1., 2., 3.]);
+        assert_eq!(mat.shape(), (0, 3));
+        assert_eq!(
+            mat.remove_row(0),
+            Err(LinalgError::IndexOutOfBounds { index: 0, bound: 0 })
+        );
+    }
+
+    #[test]
+    fn test_remove_middle_col() {
+        let mut mat = Matrix::from_fn(3, 4, |i, j| (i * 4 + j) as f64);
+        assert_eq!(mat.remove_col(2).unwrap(), vec![2., 6., 10.]);
+        assert_eq!(
+            mat,
+            Matrix::from_rows(&[&[0., 1., 3.], &[4., 5., 7.], &[8., 9., 11.]]).unwrap()
+        );
+        assert_eq!(mat.remove_row(1).unwrap(), vec![4., 5., 7.]);
+        assert!(mat.remove_col(3).is_err());
+    }
+
+    #[test]
+    fn test_cols_iter() {
+        let mat = Matrix::from_rows(&[&[1., 2.], &[3., 4.]]).unwrap();
+        let cols: Vec<Vec<f64>> = mat.cols_iter().collect();
+        assert_eq!(cols, vec![vec![1., 3.], vec![2., 4.]]);
+        assert_eq!(Matrix::zeros(0, 2).cols_iter().count(), 2);
+    }
+
+    #[test]
+    fn test_select_rows_with_repeats() {
+        let mat = Matrix::from_rows(&[&[1., 2.], &[3., 4.], &[5., 6.]]).unwrap();
+        assert_eq!(
+            mat.select_rows(&[2, 0, 0]).unwrap(),
+            Matrix::from_rows(&[&[5., 6.], &[1., 2.], &[1., 2.]]).unwrap()
+        );
+        assert_eq!(
+            mat.select_rows(&[0, 3]),
+            Err(LinalgError::IndexOutOfBounds { index: 3, bound: 3 })
+        );
+    }
+
+    #[test]
+    fn test_select_cols_and_grid() {
+        let mat = Matrix::from_fn(3, 4, |i, j| (i * 4 + j) as f64);
+        assert_eq!(
+            mat.select_cols(&[3, 1]).unwrap(),
+            Matrix::from_rows(&[&[3., 1.], &[7., 5.], &[11., 9.]]).unwrap()
+        );
+        assert_eq!(
+            mat.select(&[1, 2], &[0, 0, 2]).unwrap(),
+            Matrix::from_rows(&[&[4., 4., 6.], &[8., 8., 10.]]).unwrap()
+        );
+        assert_eq!(mat.select(&[], &[1]).unwrap().shape(), (0, 1));
+        let err = mat.select_cols(&[7]).unwrap_err();
+        assert!(err.to_string().contains("the index is 7"));
+    }
+
+    #[test]
+    fn test_stack_associated_form_across_seam() {
+        let a = Matrix::from_fn(2, 2, |i, j| (i * 2 + j) as f64);
+        let b = Matrix::from_rows(&[&[10.], &[11.]]).unwrap();
+        let aug = Matrix::hstack(&a, &b).unwrap();
+        assert_eq!(aug[(0, 1)], 1.);
+        assert_eq!(aug[(0, 2)], 10.);
+        assert_eq!(aug[(1, 2)], 11.);
+        let c = Matrix::ones(1, 3);
+        let tall = Matrix::vstack(&aug, &c).unwrap();
+        assert_eq!(tall[(1, 2)], 11.);
+        assert_eq!(tall[(2, 0)], 1.);
+    }
+
+    #[test]
+    fn test_hstack_all_and_vstack_all() {
+        let a = Matrix::ones(2, 1);
+        let b = Matrix::zeros(2, 2);
+        let c = Matrix::from_scalar(2, 1, 3.);
+        let h = Matrix::hstack_all(&[&a, &b, &c]).unwrap();
+        assert_eq!(h.row(1), &[1., 0., 0., 3.]);
+        let v = Matrix::vstack_all(&[&b, &b.clone().transpose(), &Matrix::ones(1, 2)]).unwrap();
+        assert_eq!(v.shape(), (5, 2));
+        assert_eq!(v.row(4), &[1., 1.]);
+        assert_eq!(
+            Matrix::hstack_all(&[&a, &Matrix::zeros(3, 1)]),
+            Err(LinalgError::ShapeMismatch {
+                expected: (2, 1),
+                found: (3, 1)
+            })
+        );
+        assert_eq!(
+            Matrix::vstack_all(&[&a, &b]),
+            Err(LinalgError::ShapeMismatch {
+                expected: (2, 1),
+                found: (2, 2)
+            })
+        );
+    }
+
+    #[test]
+    fn test_stack_all_empty_list() {
+        let err = Matrix::hstack_all(&[]).unwrap_err();
+        assert_eq!(err.to_string(), "Cannot stack an empty list of matrices");
+        assert!(Matrix::vstack_all(&[]).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    mod serde {
+        use super::*;
+
+        #[test]
+        fn test_json_round_trip() {
+            let mat = Matrix::from_rows(&[&[1., -2.5], &[0., 4.]]).unwrap();
+            let json = serde_json::to_string(&mat).unwrap();
+            assert_eq!(json, r#"{"rows":2,"cols":2,"data":[1.0,-2.5,0.0,4.0]}"#);
+            let back: Matrix = serde_json::from_str(&json).unwrap();
+            assert_eq!(back, mat);
+        }
+
+        #[test]
+        fn test_deserialize_rejects_wrong_length() {
+            let err = serde_json::from_str::<Matrix>(r#"{"rows":2,"cols":2,"data":[1.0,2.0,3.0]}"#)
+                .unwrap_err();
+            assert!(err.to_string().contains("expected 4 elements but found 3"));
+        }
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let mat = Matrix::from_rows(&[&[1., -2.5, 1. / 3.], &[0., 4e-10, 1e20]]).unwrap();
+        let mut buf = Vec::new();
+        mat.to_csv(&mut buf).unwrap();
+        assert_eq!(buf.iter().filter(|&&b| b == b'\n').count(), 2);
+        assert!(buf.starts_with(b"1,-2.5,0.333"));
+        assert_eq!(Matrix::from_csv(buf.as_slice()).unwrap(), mat);
+    }
+
+    #[test]
+    fn test_from_csv_whitespace_and_blank_lines() {
+        let mat = Matrix::from_csv("1, 2\n\n 3 ,4\n".as_bytes()).unwrap();
+        assert_eq!(mat, Matrix::from_rows(&[&[1., 2.], &[3., 4.]]).unwrap());
+        assert_eq!(Matrix::from_csv("".as_bytes()).unwrap().shape(), (0, 0));
+    }
+
+    #[test]
+    fn test_csv_round_trip_without_columns() {
+        for shape in [(3, 0), (0, 0)] {
+            let mat = Matrix::zeros(shape.0, shape.1);
+            let mut buf = Vec::new();
+            mat.to_csv(&mut buf).unwrap();
+            assert_eq!(Matrix::from_csv(buf.as_slice()).unwrap(), mat);
+        }
+        let mut buf = Vec::new();
+        Matrix::zeros(0, 4).to_csv(&mut buf).unwrap();
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn test_from_csv_errors() {
+        assert_eq!(
+            Matrix::from_csv("1,2\n3,4,5\n".as_bytes()),
+            Err(LinalgError::InconsistentRow {
+                row: 1,
+                expected: 2,
+                found: 3
+            })
+        );
+        let err = Matrix::from_csv("1,2\n3,x\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parse error: Invalid number \"x\" on line 2"
+        );
+    }
+
+    #[test]
+    fn test_split_at_row_round_trip() {
+        let mat = Matrix::from_fn(4, 3, |i, j| (i * 3 + j) as f64);
+        for k in 0..=4 {
+            let (top, bottom) = mat.clone().split_at_row(k);
+            assert_eq!(top.shape(), (k, 3));
+            assert_eq!(bottom.shape(), (4 - k, 3));
+            assert_eq!(top.vstack(&bottom).unwrap(), mat);
+        }
+    }
+
+    #[test]
+    fn test_split_at_col_round_trip() {
+        let mat = Matrix::from_fn(3, 4, |i, j| (i * 4 + j) as f64);
+        for k in 0..=4 {
+            let (left, right) = mat.clone().split_at_col(k);
+            assert_eq!(left.shape(), (3, k));
+            assert_eq!(right.shape(), (3, 4 - k));
+            assert_eq!(left.hstack(&right).unwrap(), mat);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot split a matrix with 2 rows at row 3.")]
+    fn test_split_at_row_out_of_range() {
+        Matrix::zeros(2, 2).split_at_row(3);
+    }
+
+    #[test]
+    fn test_is_symmetric() {
+        let sym = Matrix::from_rows(&[&[2., -1., 0.], &[-1., 2., -1.], &[0., -1., 2.]]).unwrap();
+        assert!(sym.is_symmetric(0.));
+        let mut near = sym.clone();
+        near[(0, 1)] += 1e-9;
+        assert!(!near.is_symmetric(0.));
+        assert!(near.is_symmetric(1e-6));
+        assert!(!Matrix::zeros(2, 3).is_symmetric(1.));
+        assert!(Matrix::zeros(0, 0).is_symmetric(0.));
+    }
+
+    #[test]
+    fn test_resize_grow_then_shrink() {
+        let orig = Matrix::from_rows(&[&[1., 2.], &[3., 4.]]).unwrap();
+        let mut mat = orig.clone();
+        mat.resize(3, 4, 9.);
+        assert_eq!(
+            mat,
+            Matrix::from_rows(&[&[1., 2., 9., 9.], &[3., 4., 9., 9.], &[9., 9., 9., 9.]]).unwrap()
+        );
+        mat.resize(2, 2, 0.);
+        assert_eq!(mat, orig);
+    }
+
+    #[test]
+    fn test_resize_mixed_and_to_zero() {
+        let mut mat = Matrix::from_fn(3, 3, |i, j| (i * 3 + j) as f64);
+        mat.resize(2, 4, -1.);
+        assert_eq!(mat.row(1), &[3., 4., 5., -1.]);
+        mat.resize(0, 4, 0.);
+        assert_eq!(mat.shape(), (0, 4));
+        mat.resize(2, 0, 0.);
+        assert_eq!(mat.shape(), (2, 0));
+        mat.resize(1, 1, 5.);
+        assert_eq!(mat, Matrix::from_scalar(1, 1, 5.));
+    }
+
+    #[test]
+    fn test_is_square() {
+        assert!(Matrix::identity(3).is_square());
+        assert!(Matrix::zeros(0, 0).is_square());
+        assert!(!Matrix::zeros(2, 3).is_square());
+        assert!(!Matrix::zeros(3, 1).is_square());
+    }
+
+    #[test]
+    fn test_set_block_in_each_corner() {
+        let base = Matrix::from_fn(4, 4, |i, j| (i * 4 + j) as f64);
+        let block = Matrix::from_scalar(2, 2, -1.);
+        for &(r, c) in &[(0, 0), (0, 2), (2, 0), (2, 2)] {
+            let mut mat = base.clone();
+            mat.set_block(r, c, &block).unwrap();
+            for i in 0..4 {
+                for j in 0..4 {
+                    let inside = (r..r + 2).contains(&i) && (c..c + 2).contains(&j);
+                    let expected = if inside { -1. } else { base[(i, j)] };
+                    assert_eq!(mat[(i, j)], expected);
+                }
+            }
+            assert_eq!(mat.get_block(r, c, 2, 2).unwrap(), block);
+        }
+    }
+
+    #[test]
+    fn test_set_block_errors_and_empty() {
+        let mut mat = Matrix::zeros(4, 4);
+        let err = mat.set_block(3, 0, &Matrix::ones(2, 2)).unwrap_err();
+        assert_eq!(err.to_string(), "Row range 3..5 is not within 0..4");
+        assert!(mat.set_block(0, 3, &Matrix::ones(1, 2)).is_err());
+        assert!(mat.get_block(1, 1, 1, 4).is_err());
+        mat.set_block(2, 2, &Matrix::zeros(0, 0)).unwrap();
+        assert_eq!(mat, Matrix::zeros(4, 4));
+        assert_eq!(
+            mat.get_block(usize::MAX, 0, 2, 1),
+            Err(LinalgError::IndexOutOfBounds {
+                index: usize::MAX,
+                bound: 4
+            })
+        );
+        assert_eq!(
+            mat.set_block(0, usize::MAX, &Matrix::ones(1, 1)),
+            Err(LinalgError::IndexOutOfBounds {
+                index: usize::MAX,
+                bound: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_set_block_empty_out_of_range() {
+        let mut mat = Matrix::zeros(2, 2);
+        mat.set_block(10, 10, &Matrix::zeros(0, 0)).unwrap();
+        mat.set_block(5, 0, &Matrix::zeros(0, 2)).unwrap();
+        mat.set_block(0, 7, &Matrix::zeros(3, 0)).unwrap();
+        assert_eq!(mat, Matrix::zeros(2, 2));
+    }
+
+    #[test]
+    fn test_submatrix_set_block_round_trip() {
+        let orig = Matrix::from_fn(5, 5, |i, j| (i * 5 + j) as f64);
+        let mut mat = orig.clone();
+        let sub = mat.submatrix(1..4, 2..4).unwrap();
+        mat.set_block(1, 2, &(sub.clone() * 2.)).unwrap();
+        mat.set_block(1, 2, &sub).unwrap();
+        assert_eq!(mat, orig);
+    }
+
+    #[test]
+    fn test_display_precision() {
+        let mat = Matrix::from_scalar(1, 1, 1.23456);
+        assert_eq!(format!("{:.2}", mat), "1.23\nShape: 1x1");
+        assert_eq!(format!("{}", mat), "1.23456\nShape: 1x1");
+        let mat = Matrix::from_rows(&[&[9.996, 1.5]]).unwrap();
+        assert_eq!(format!("{:.1}", mat), "10.0  1.5\nShape: 1x2");
+    }
+
+    #[test]
+    fn test_fill_and_fill_with() {
+        let mut mat = Matrix::zeros(2, 3);
+        mat.fill(4.);
+        assert_eq!(mat, Matrix::from_scalar(2, 3, 4.));
+        let mut visited = Vec::new();
+        let mut count = 0.;
+        mat.fill_with(|i, j| {
+            visited.push((i, j));
+            count += 1.;
+            count
+        });
+        assert_eq!(
+            visited,
+            vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)]
+        );
+        assert_eq!(mat, Matrix::from_fn(2, 3, |i, j| (i * 3 + j + 1) as f64));
+    }
+
+    #[test]
+    fn test_fill_diagonal_rectangular() {
+        let mut wide = Matrix::ones(2, 4);
+        wide.fill_diagonal(7.);
+        assert_eq!(
+            wide,
+            Matrix::from_rows(&[&[7., 1., 1., 1.], &[1., 7., 1., 1.]]).unwrap()
+        );
+        let mut tall = Matrix::zeros(3, 2);
+        tall.fill_diagonal(-1.);
+        assert_eq!(tall.diagonal(), vec![-1., -1.]);
+        assert_eq!(tall[(2, 1)], 0.);
+    }
+
+    #[test]
+    fn test_set_diagonal() {
+        let mut mat = Matrix::zeros(3, 2);
+        mat.set_diagonal(&[1., 2.]).unwrap();
+        assert_eq!(
+            mat,
+            Matrix::from_rows(&[&[1., 0.], &[0., 2.], &[0., 0.]]).unwrap()
+        );
+        assert_eq!(
+            mat.set_diagonal(&[1., 2., 3.]),
+            Err(LinalgError::LengthMismatch {
+                expected: 2,
+                found: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_display_aligns_negative_numbers() {
+        let mat = Matrix::from_rows(&[&[-1000., 5.], &[20., -3.]]).unwrap();
+        let text = mat.to_string();
+        let lines: Vec<&str> = text.lines().take(2).collect();
+        assert_eq!(lines, vec!["-1000     5", "   20    -3"]);
+        assert_eq!(lines[0].len(), lines[1].len());
+    }
+
+    #[test]
+    fn test_display_aligns_negative_zero() {
+        let mat = Matrix::from_rows(&[&[-0.001, 10.], &[-0.4, 2.]]).unwrap();
+        assert_eq!(
+            format!("{:.2}", mat),
+            "-0.00 10.00\n-0.40  2.00\nShape: 2x2"
+        );
+        assert_eq!(format!("{:.0}", mat), "-0 10\n-0  2\nShape: 2x2");
+        let tiny = Matrix::from_rows(&[&[-1e-10], &[12.]]).unwrap();
+        let text = tiny.to_string();
+        let lines: Vec<&str> = text.lines().take(2).collect();
+        assert_eq!(lines, vec!["-0.0000000001", "12"]);
+    }
+
+    #[test]
+    fn test_triu_tril_reconstruct() {
+        let mat = Matrix::from_fn(4, 3, |i, j| (i * 3 + j + 1) as f64);
+        assert_eq!(mat.triu(0) + mat.tril(-1), mat);
+        assert_eq!(mat.triu(2) + mat.tril(1), mat);
+        let square = Matrix::from_fn(3, 3, |i, j| (i * 3 + j) as f64);
+        let sym = square.tril(0) + square.tril(-1).transpose();
+        assert!(sym.is_symmetric(0.));
+        assert_eq!(sym.tril(0), square.tril(0));
+    }
+
+    #[test]
+    fn test_tril_triu_offsets_on_wide_matrix() {
+        let mat = Matrix::ones(3, 5);
+        assert_eq!(
+            mat.triu(2),
+            Matrix::from_rows(&[
+                &[0., 0., 1., 1., 1.],
+                &[0., 0., 0., 1., 1.],
+                &[0., 0., 0., 0., 1.]
+            ])
+            .unwrap()
+        );
+        assert_eq!(
+            mat.tril(2),
+            Matrix::from_rows(&[
+                &[1., 1., 1., 0., 0.],
+                &[1., 1., 1., 1., 0.],
+                &[1., 1., 1., 1., 1.]
+            ])
+            .unwrap()
+        );
+        assert_eq!(mat.tril(-3), Matrix::zeros(3, 5));
+        assert_eq!(mat.triu(-2), mat);
+        let mut m = mat.clone();
+        m.tril_mut(0);
+        m.triu_mut(0);
+        assert_eq!(m, Matrix::eye(3, 5, 0));
+    }
+
+    #[test]
+    fn test_display_aligns_values_below_one() {
+        let mat = Matrix::from_cols(&[&[0.1, 0.25, 0.999, 10., -0.5]]).unwrap();
+        assert_eq!(mat.to_string(), " 0.1\n 0.25\n 0.999\n10\n-0.5\nShape: 5x1");
+        let probs = Matrix::from_rows(&[&[0.1, 0.25], &[0.999, 0.5]]).unwrap();
+        assert_eq!(probs.to_string(), "0.1   0.25\n0.999 0.5\nShape: 2x2");
+    }
+
+    #[test]
+    fn test_diagonal_offset() {
+        let mat = Matrix::from_fn(3, 4, |i, j| (i * 4 + j) as f64);
+        assert_eq!(mat.diagonal_offset(0), mat.diagonal());
+        assert_eq!(mat.diagonal_offset(1), vec![1., 6., 11.]);
+        assert_eq!(mat.diagonal_offset(3), vec![3.]);
+        assert_eq!(mat.diagonal_offset(-2), vec![8.]);
+        assert!(mat.diagonal_offset(4).is_empty());
+        assert!(mat.diagonal_offset(-3).is_empty());
+    }
+
+    #[test]
+    fn test_trace_of_identity() {
+        for n in 0..5 {
+            assert_eq!(Matrix::identity(n).trace().unwrap(), n as f64);
+        }
+        assert_eq!(Matrix::zeros(3, 4).trace(), Err(LinalgError::NotSquare));
+    }
+
+    #[test]
+    fn test_flips() {
+        let mat = Matrix::from_rows(&[&[1., 2., 3.], &[4., 5., 6.]]).unwrap();
+        assert_eq!(
+            mat.fliplr(),
+            Matrix::from_rows(&[&[3., 2., 1.], &[6., 5., 4.]]).unwrap()
+        );
+        assert_eq!(
+            mat.flipud(),
+            Matrix::from_rows(&[&[4., 5., 6.], &[1., 2., 3.]]).unwrap()
+        );
+        let row = Matrix::from_rows(&[&[1., 2., 3.]]).unwrap();
+        assert_eq!(row.flipud(), row);
+    }
+
+    #[test]
+    fn test_rot90() {
+        let mat = Matrix::from_rows(&[&[1., 2., 3.], &[4., 5., 6.]]).unwrap();
+        let once = mat.rot90(1);
+        assert_eq!(once.shape(), (3, 2));
+        assert_eq!(
+            once,
+            Matrix::from_rows(&[&[3., 6.], &[2., 5.], &[1., 4.]]).unwrap()
+        );
+        assert_eq!(
+            mat.rot90(-1),
+            Matrix::from_rows(&[&[4., 1.], &[5., 2.], &[6., 3.]]).unwrap()
+        );
+        assert_eq!(mat.rot90(2), mat.fliplr().flipud());
+        assert_eq!(mat.rot90(3), mat.rot90(-1));
+        assert_eq!(once.rot90(1).rot90(1).rot90(1), mat);
+        assert_eq!(mat.rot90(4), mat);
+    }
+
+    #[test]
+    fn test_minor_every_position() {
+        let mat = Matrix::from_fn(3, 3, |i, j| (i * 3 + j + 1) as f64);
+        for i in 0..3 {
+            for j in 0..3 {
+                let minor = mat.minor(i, j).unwrap();
+                let rows: Vec<usize> = (0..3).filter(|&r| r != i).collect();
+                let cols: Vec<usize> = (0..3).filter(|&c| c != j).collect();
+                assert_eq!(minor, mat.select(&rows, &cols).unwrap());
+            }
+        }
+        assert_eq!(
+            mat.minor(1, 1).unwrap(),
+            Matrix::from_rows(&[&[1., 3.], &[7., 9.]]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_minor_errors() {
+        let mat = Matrix::zeros(3, 4);
+        assert_eq!(
+            mat.minor(3, 0),
+            Err(LinalgError::IndexOutOfBounds { index: 3, bound: 3 })
+        );
+        assert_eq!(
+            mat.minor(0, 4),
+            Err(LinalgError::IndexOutOfBounds { index: 4, bound: 4 })
+        );
+        assert!(Matrix::zeros(1, 3).minor(0, 0).is_err());
+        assert_eq!(mat.minor(2, 3).unwrap().shape(), (2, 3));
+    }
+
+    #[test]
+    fn test_push_rows_then_col() {
+        let mut mat = Matrix::zeros(0, 0);
+        mat.push_row(&[1., 2.]).unwrap();
+        mat.push_row(&[3., 4.]).unwrap();
+        mat.push_row(&[5., 6.]).unwrap();
+        assert_eq!(mat.shape(), (3, 2));
+        assert_eq!(mat, Matrix::from_fn(3, 2, |i, j| (i * 2 + j + 1) as f64));
+        mat.push_col(&[-1., -2., -3.]).unwrap();
+        assert_eq!(mat.shape(), (3, 3));
+        assert_eq!(mat.col(2), vec![-1., -2., -3.]);
+        assert_eq!(mat.row(1), &[3., 4., -2.]);
+    }
+
+    #[test]
+    fn test_push_length_mismatch() {
+        let mut mat = Matrix::zeros(2, 3);
+        assert_eq!(
+            mat.push_row(&[1., 2.]),
+            Err(LinalgError::LengthMismatch {
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            mat.push_col(&[1., 2., 3.]),
+            Err(LinalgError::LengthMismatch {
+                expected: 2,
+                found: 3
+            })
+        );
+        assert_eq!(mat, Matrix::zeros(2, 3));
+    }
+
+    #[test]
+    fn test_row_indexing() {
+        let mut mat = Matrix::from_fn(2, 3, |i, j| (i * 3 + j) as f64);
+        assert_eq!(mat[1][0], mat[(1, 0)]);
+        assert_eq!(&mat[1], mat.row(1));
+        mat[0][2] = 5.;
+        assert_eq!(mat[(0, 2)], 5.);
+        let total: f64 = mat[0].iter().sum();
+        assert_eq!(total, 6.);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the shape is (2, 3) but the row index is 2.")]
+    fn test_row_indexing_out_of_bounds() {
+        let mat = Matrix::zeros(2, 3);
+        let _ = &mat[2];
+    }
+
+    #[test]
+    fn test_symmetric_eig_diagonal() {
+        let mat = Matrix::from_diag(&[3., -1., 2.]);
+        let (values, vectors) = mat.symmetric_eig(10, 1e-12).unwrap();
+        assert_eq!(values, vec![-1., 2., 3.]);
+        assert_eq!(
+            vectors,
+            Matrix::permutation(&[1, 2, 0]).unwrap().transpose()
+        );
+    }
+
+    #[test]
+    fn test_symmetric_eig_2x2() {
+        let mat = Matrix::from_rows(&[&[2., 1.], &[1., 2.]]).unwrap();
+        let (values, vectors) = mat.symmetric_eig(10, 1e-12).unwrap();
+        assert!((values[0] - 1.).abs() < 1e-12);
+        assert!((values[1] - 3.).abs() < 1e-12);
+        let h = 0.5_f64.sqrt();
+        assert!((vectors[(0, 0)].abs() - h).abs() < 1e-12);
+        assert!((vectors[(0, 0)] + vectors[(1, 0)]).abs() < 1e-12);
+        assert!((vectors[(0, 1)] - vectors[(1, 1)]).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_symmetric_eig_reconstructs() {
+        let mat = Matrix::from_rows(&[&[4., 1., -2.], &[1., 2., 0.], &[-2., 0., 3.]]).unwrap();
+        let (values, vectors) = mat.symmetric_eig(50, 1e-14).unwrap();
+        let rebuilt = &(&vectors * &Matrix::from_diag(&values)) * &vectors.clone().transpose();
+        assert!(approx_eq(&rebuilt, &mat, 1e-10));
+        let gram = &vectors.clone().transpose() * &vectors;
+        assert!(approx_eq(&gram, &Matrix::identity(3), 1e-12));
+        assert!(values.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn test_symmetric_eig_errors() {
+        let mat = Matrix::from_rows(&[&[1., 2.], &[0., 1.]]).unwrap();
+        let err = mat.symmetric_eig(10, 1e-12).unwrap_err();
+        assert_eq!(err.to_string(), "Matrix is not symmetric");
+        let tiny = Matrix::from_rows(&[&[2., 5.], &[0., 2.]]).unwrap() * 1e-13;
+        assert!(matches!(
+            tiny.symmetric_eig(10, 1e-12),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+        assert_eq!(
+            Matrix::zeros(2, 3).symmetric_eig(10, 1e-12),
+            Err(LinalgError::NotSquare)
+        );
+        let dense = Matrix::from_fn(6, 6, |i, j| 1. / (1. + i as f64 + j as f64));
+        assert_eq!(
+            dense.symmetric_eig(0, 1e-14),
+            Err(LinalgError::NotConverged { iterations: 0 })
+        );
+    }
+
+    #[test]
+    fn test_power_iteration() {
+        // Eigenvalues 5 and 2, with (1, 1) for the dominant one.
+        let mat = Matrix::from_rows(&[&[4., 1.], &[2., 3.]]).unwrap();
+        let (value, vector) = mat.power_iteration(200, 1e-12).unwrap();
+        assert!((value - 5.).abs() < 1e-9);
+        let h = 0.5_f64.sqrt();
+        assert!((vector[0] - h).abs() < 1e-6);
+        assert!((vector[1] - h).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_power_iteration_errors() {
+        assert_eq!(
+            Matrix::ones(2, 3).power_iteration(10, 1e-9),
+            Err(LinalgError::NotSquare)
+        );
+        let mat = Matrix::from_rows(&[&[1., 0.], &[0., 0.99]]).unwrap();
+        assert_eq!(
+            mat.power_iteration(3, 1e-15),
+            Err(LinalgError::NotConverged { iterations: 3 })
+        );
+    }
+
+    #[test]
+    fn test_power_iteration_equal_row_sums() {
+        // Eigenvalues 3 and 1. The all-ones vector is the eigenvector for 1.
+        let mat = Matrix::from_rows(&[&[2., -1.], &[-1., 2.]]).unwrap();
+        let (value, vector) = mat.power_iteration(200, 1e-12).unwrap();
+        assert!((value - 3.).abs() < 1e-9);
+        let h = 0.5_f64.sqrt();
+        assert!((vector[0] - h).abs() < 1e-6);
+        assert!((vector[1] + h).abs() < 1e-6);
+
+        let opposite = Matrix::from_rows(&[&[2., 0.], &[0., -2.]]).unwrap();
+        assert_eq!(
+            opposite.power_iteration(200, 1e-12),
+            Err(LinalgError::NotConverged { iterations: 200 })
+        );
+    }
+
+    #[test]
+    fn test_iter_row_major_order() {
+        let mat = Matrix::from_fn(2, 3, |i, j| (10 * i + j) as f64);
+        let elems: Vec<f64> = mat.iter().copied().collect();
+        for i in 0..2 {
+            for j in 0..3 {
+                assert_eq!(elems[i * 3 + j], mat[(i, j)]);
+            }
+        }
+        assert_eq!(mat.iter().sum::<f64>(), 36.);
+        let borrowed: Vec<f64> = (&mat).into_iter().copied().collect();
+        assert_eq!(borrowed, elems);
+        let owned: Vec<f64> = mat.into_iter().collect();
+        assert_eq!(owned, elems);
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut mat = Matrix::from_fn(2, 2, |i, j| (i * 2 + j) as f64);
+        for x in mat.iter_mut() {
+            *x *= 2.;
+        }
+        for x in &mut mat {
+            *x += 1.;
+        }
+        assert_eq!(mat, Matrix::from_rows(&[&[1., 3.], &[5., 7.]]).unwrap());
+        let mut count = 0;
+        for _ in &mat {
+            count += 1;
+        }
+        assert_eq!(count, 4);
+    }
+
+    #[test]
+    fn test_rows_iterator() {
+        let mat = Matrix::from_fn(3, 2, |i, j| (i * 2 + j) as f64);
+        assert_eq!(mat.rows().count(), mat.shape().0);
+        assert_eq!(mat.rows().len(), 3);
+        let reversed: Vec<&[f64]> = mat.rows().rev().collect();
+        assert_eq!(reversed, vec![&[4., 5.][..], &[2., 3.], &[0., 1.]]);
+        let means: Vec<f64> = mat.rows().map(|r| r.iter().sum::<f64>() / 2.).collect();
+        assert_eq!(means, vec![0.5, 2.5, 4.5]);
+    }
+
+    #[test]
+    fn test_rows_mut() {
+        let mut mat = Matrix::zeros(3, 2);
+        for (i, row) in mat.rows_mut().enumerate() {
+            row[1] = i as f64;
+        }
+        {
+            let mut iter = mat.rows_mut();
+            assert_eq!(iter.len(), 3);
+            let last = iter.next_back().unwrap();
+            let first = iter.next().unwrap();
+            first[0] = -1.;
+            last[0] = -3.;
+            assert_eq!(iter.len(), 1);
+        }
+        assert_eq!(
+            mat,
+            Matrix::from_rows(&[&[-1., 0.], &[0., 1.], &[-3., 2.]]).unwrap()
+        );
+        let mut empty_rows = Matrix::zeros(4, 0);
+        assert_eq!(empty_rows.rows_mut().count(), 4);
+        assert_eq!(empty_rows.rows_mut().rev().count(), 4);
+    }
+
+    #[test]
+    fn test_cols_of_transpose_are_rows() {
+        let mat = Matrix::from_fn(2, 3, |i, j| (i * 3 + j) as f64);
+        let t = mat.clone().transpose();
+        let cols: Vec<Vec<f64>> = t.cols().collect();
+        let rows: Vec<Vec<f64>> = mat.rows().map(|r| r.to_vec()).collect();
+        assert_eq!(cols, rows);
+        assert_eq!(t.cols().len(), 2);
+        assert_eq!(t.cols().next_back().unwrap(), vec![3., 4., 5.]);
+    }
+
+    #[test]
+    fn test_pseudo_inverse_tall_full_rank() {
+        let a = Matrix::from_rows(&[&[1., 2.], &[3., 4.], &[5., 7.]]).unwrap();
+        let pinv = a.pseudo_inverse(1e-10).unwrap();
+        assert_eq!(pinv.shape(), (2, 3));
+        assert!(approx_eq(&(&pinv * &a), &Matrix::identity(2), 1e-9));
+        assert!(approx_eq(&(&(&a * &pinv) * &a), &a, 1e-9));
+    }
+
+    #[test]
+    fn test_pseudo_inverse_square_and_wide() {
+        let a = Matrix::from_rows(&[&[2., 1.], &[1., 3.]]).unwrap();
+        assert!(approx_eq(
+            &a.pseudo_inverse(1e-10).unwrap(),
+            &a.inverse().unwrap(),
+            1e-10
+        ));
+        let wide = Matrix::from_rows(&[&[1., 0., 2.], &[0., 1., 1.]]).unwrap();
+        let pinv = wide.pseudo_inverse(1e-10).unwrap();
+        assert_eq!(pinv.shape(), (3, 2));
+        assert!(approx_eq(&(&wide * &pinv), &Matrix::identity(2), 1e-9));
+    }
+
+    #[test]
+    fn test_pseudo_inverse_rank_deficient() {
+        // Rank one: the pseudoinverse of x yᵀ is y xᵀ / (|x|² |y|²).
+        let a = Matrix::from_rows(&[&[1., 2.], &[2., 4.], &[3., 6.]]).unwrap();
+        let pinv = a.pseudo_inverse(1e-8).unwrap();
+        let expected = a.clone().transpose() * (1. / 70.);
+        assert!(approx_eq(&pinv, &expected, 1e-9));
+    }
+
+    #[test]
+    fn test_pseudo_inverse_ill_conditioned() {
+        // A tall Hilbert matrix has a condition number near 1e7, which squaring through AᵀA
+        // would push to 1e14.
+        let hilbert = Matrix::from_fn(8, 6, |i, j| 1. / (1. + i as f64 + j as f64));
+        let pinv = hilbert.pseudo_inverse(1e-15).unwrap();
+        assert!(approx_eq(&(&pinv * &hilbert), &Matrix::identity(6), 1e-6));
+    }
+
+    #[test]
+    fn test_indexed_iter() {
+        let mat = Matrix::from_rows(&[&[1., 2., 3.], &[4., 5., 6.]]).unwrap();
+        let collected: Vec<((usize, usize), f64)> =
+            mat.indexed_iter().map(|(idx, &x)| (idx, x)).collect();
+        assert_eq!(
+            collected,
+            vec![
+                ((0, 0), 1.),
+                ((0, 1), 2.),
+                ((0, 2), 3.),
+                ((1, 0), 4.),
+                ((1, 1), 5.),
+                ((1, 2), 6.)
+            ]
+        );
+        let big: Vec<(usize, usize)> = mat
+            .indexed_iter()
+            .filter(|(_, &x)| x > 4.)
+            .map(|(idx, _)| idx)
+            .collect();
+        assert_eq!(big, vec![(1, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn test_indexed_iter_mut_zero_off_diagonal() {
+        let mut mat = Matrix::ones(3, 3);
+        for ((i, j), x) in mat.indexed_iter_mut() {
+            if i != j {
+                *x = 0.;
+            }
+        }
+        assert_eq!(mat, Matrix::identity(3));
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let mat = Matrix::from_iter(2, 3, (0..6).map(|x| x as f64)).unwrap();
+        assert_eq!(
+            mat,
+            Matrix::from_rows(&[&[0., 1., 2.], &[3., 4., 5.]]).unwrap()
+        );
+        let doubled = Matrix::from_iter(2, 3, mat.iter().map(|x| x * 2.)).unwrap();
+        assert_eq!(doubled, mat.clone() * 2.);
+        let ones = Matrix::from_iter(2, 2, std::iter::repeat(1.)).unwrap();
+        assert_eq!(ones, Matrix::ones(2, 2));
+    }
+
+    #[test]
+    fn test_from_iter_too_short() {
+        let err = Matrix::from_iter(2, 3, vec![1., 2., 3., 4.]).unwrap_err();
+        assert_eq!(
+            err,
+            LinalgError::LengthMismatch {
+                expected: 6,
+                found: 4
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "Length mismatch: expected 6 elements but found 4"
+        );
+    }
+
+    #[test]
+    fn test_lstsq_line_fit() {
+        // Points near y = 2x + 1.
+        let a = Matrix::from_rows(&[&[1., 0.], &[1., 1.], &[1., 2.]]).unwrap();
+        let b = Matrix::from_cols(&[&[1.05, 2.95, 5.02]]).unwrap();
+        let x = a.lstsq(&b).unwrap();
+        assert_eq!(x.shape(), (2, 1));
+        assert!((x[(0, 0)] - 1.).abs() < 0.1);
+        assert!((x[(1, 0)] - 2.).abs() < 0.1);
+        let residual = &(&a * &x) + &(-b.clone());
+        assert!(residual.frobenius_norm() < 0.1);
+        // The residual is orthogonal to the columns of `a`.
+        let normal = &a.clone().transpose() * &residual;
+        assert!(normal.frobenius_norm() < 1e-12);
+    }
+
+    #[test]
+    fn test_lstsq_tiny_scale() {
+        let a = Matrix::from_rows(&[&[1., 0.], &[1., 1.], &[1., 2.]]).unwrap() * 1e-13;
+        let expected = Matrix::from_cols(&[&[1., 2.]]).unwrap();
+        let b = &a * &expected;
+        assert!(approx_eq(&a.lstsq(&b).unwrap(), &expected, 1e-12));
+    }
+
+    #[test]
+    fn test_lstsq_errors() {
+        let a = Matrix::ones(3, 2);
+        assert_eq!(
+            a.lstsq(&Matrix::ones(2, 1)),
+            Err(LinalgError::ShapeMismatch {
+                expected: (3, 1),
+                found: (2, 1)
+            })
+        );
+        assert_eq!(a.lstsq(&Matrix::ones(3, 1)), Err(LinalgError::Singular));
+    }
+
+    #[test]
+    fn test_solve_lower() {
+        let l = Matrix::from_rows(&[&[2., 0., 0.], &[1., 3., 0.], &[-1., 2., 4.]]).unwrap();
+        let b = Matrix::from_cols(&[&[4., 5., 9.]]).unwrap();
+        // x0 = 2, x1 = (5 - 2) / 3 = 1, x2 = (9 + 2 - 2) / 4 = 2.25
+        let x = l.solve_lower(&b).unwrap();
+        assert_eq!(x, Matrix::from_cols(&[&[2., 1., 2.25]]).unwrap());
+    }
+
+    #[test]
+    fn test_solve_upper() {
+        let u = Matrix::from_rows(&[&[1., 2., -1.], &[0., 2., 1.], &[0., 0., 4.]]).unwrap();
+        let b = Matrix::from_rows(&[&[2., 1.], &[5., 0.], &[8., 4.]]).unwrap();
+        // Column 0: x2 = 2, x1 = 1.5, x0 = 2 - 3 + 2 = 1.
+        // Column 1: x2 = 1, x1 = -0.5, x0 = 1 + 1 + 1 = 3.
+        let x = u.solve_upper(&b).unwrap();
+        assert_eq!(
+            x,
+            Matrix::from_rows(&[&[1., 3.], &[1.5, -0.5], &[2., 1.]]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_triangular_solve_tiny_scale() {
+        let l = Matrix::from_rows(&[&[2., 0., 0.], &[1., 3., 0.], &[-1., 2., 4.]]).unwrap() * 1e-13;
+        let b = Matrix::from_cols(&[&[4., 5., 9.]]).unwrap() * 1e-13;
+        let expected = Matrix::from_cols(&[&[2., 1., 2.25]]).unwrap();
+        assert!(approx_eq(&l.solve_lower(&b).unwrap(), &expected, 1e-12));
+        let expected = Matrix::from_cols(&[&[2.25, 1., 2.]]).unwrap();
+        let u = Matrix::from_fn(3, 3, |i, j| l[(2 - i, 2 - j)]);
+        let b = Matrix::from_fn(3, 1, |i, j| b[(2 - i, j)]);
+        assert!(approx_eq(&u.solve_upper(&b).unwrap(), &expected, 1e-12));
+    }
+
+    #[test]
+    fn test_triangular_solve_errors() {
+        let l = Matrix::from_rows(&[&[1., 0.], &[1., 0.]]).unwrap();
+        let b = Matrix::ones(2, 1);
+        assert_eq!(l.solve_lower(&b), Err(LinalgError::Singular));
+        assert_eq!(l.transpose().solve_upper(&b), Err(LinalgError::Singular));
+        assert_eq!(
+            Matrix::ones(2, 3).solve_upper(&b),
+            Err(LinalgError::NotSquare)
+        );
+        assert!(Matrix::identity(3).solve_lower(&b).is_err());
+    }
+
+    #[test]
+    fn test_map_squares_and_map_inplace() {
+        let mut mat = Matrix::from_rows(&[&[1., -2.], &[3., -4.]]).unwrap();
+        assert_eq!(
+            mat.map(|x| x * x),
+            Matrix::from_rows(&[&[1., 4.], &[9., 16.]]).unwrap()
+        );
+        mat.map_inplace(f64::abs);
+        assert_eq!(mat, Matrix::from_rows(&[&[1., 2.], &[3., 4.]]).unwrap());
+    }
+
+    #[test]
+    fn test_zip_map() {
+        let a = Matrix::from_rows(&[&[1., 5.], &[-3., 0.]]).unwrap();
+        let b = Matrix::from_rows(&[&[2., 4.], &[-4., 0.5]]).unwrap();
+        assert_eq!(
+            a.zip_map(&b, f64::max).unwrap(),
+            Matrix::from_rows(&[&[2., 5.], &[-3., 0.5]]).unwrap()
+        );
+        assert_eq!(
+            a.zip_map(&b, |x, y| x * y).unwrap(),
+            Matrix::from_rows(&[&[2., 20.], &[12., 0.]]).unwrap()
+        );
+        assert_eq!(
+            a.zip_map(&Matrix::ones(2, 3), f64::max),
+            Err(LinalgError::ShapeMismatch {
+                expected: (2, 2),
+                found: (2, 3)
+            })
+        );
+    }
+
+    #[test]
+    fn test_swap_rows_2x2() {
+        let mut mat = Matrix::from_rows(&[&[1., 2.], &[3., 4.]]).unwrap();
+        mat.swap_rows(0, 1);
+        assert_eq!(mat, Matrix::from_rows(&[&[3., 4.], &[1., 2.]]).unwrap());
+    }
+
+    #[test]
+    fn test_sum_of_matrices() {
+        let mat = Matrix::from_rows(&[&[1., -2.], &[0.5, 4.]]).unwrap();
+        let owned: Matrix = vec![mat.clone(), mat.clone(), mat.clone()]
+            .into_iter()
+            .sum();
+        assert_eq!(owned, mat.clone() * 3.);
+        let borrowed: Matrix = [&mat, &mat, &mat].into_iter().sum();
+        assert_eq!(borrowed, mat * 3.);
+    }
+
+    #[test]
+    #[should_panic(expected = "Left(2, 2), Right(2, 3)")]
+    fn test_sum_mismatched_shapes() {
+        let _: Matrix = vec![Matrix::ones(2, 2), Matrix::ones(2, 3)]
+            .into_iter()
+            .sum();
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot sum an empty iterator of matrices")]
+    fn test_sum_empty() {
+        let _: Matrix = Vec::<Matrix>::new().into_iter().sum();
+    }
+
+    #[test]
+    fn test_product_of_matrices() {
+        let mat = Matrix::from_rows(&[&[1., 1.], &[1., 0.]]).unwrap();
+        for k in 1..6 {
+            let owned: Matrix = std::iter::repeat_n(mat.clone(), k).product();
+            assert_eq!(owned, mat.pow(k as i64));
+            let borrowed: Matrix = std::iter::repeat_n(&mat, k).product();
+            assert_eq!(borrowed, mat.pow(k as i64));
+        }
+        let a = Matrix::ones(2, 3);
+        let b = Matrix::ones(3, 1);
+        let chained: Matrix = [&a, &b].into_iter().product();
+        assert_eq!(chained, Matrix::from_scalar(2, 1, 3.));
+    }
+
+    #[cfg(feature = "rayon")]
+    mod parallel {
+        use super::*;
+        use rayon::prelude::*;
+
+        fn large() -> Matrix {
+            Matrix::from_fn(1000, 1000, |i, j| {
+                (i as f64).sin() * (j as f64 + 0.5).sqrt()
+            })
+        }
+
+        #[test]
+        fn test_par_map_inplace_matches_serial() {
+            let mut serial = large();
+            let mut parallel = serial.clone();
+            serial.map_inplace(|x| x * 2.);
+            parallel.par_map_inplace(|x| x * 2.);
+            assert_eq!(parallel, serial);
+        }
+
+        #[test]
+        fn test_par_iter_and_par_iter_mut() {
+            let mat = large();
+            let doubled: Vec<f64> = mat.par_iter().map(|x| x * 2.).collect();
+            assert_eq!(doubled, mat.map(|x| x * 2.).into_vec());
+            let mut par = mat.clone();
+            par.par_iter_mut().for_each(|x| *x *= 2.);
+            assert_eq!(par, mat.map(|x| x * 2.));
+        }
+
+        #[test]
+        fn test_par_rows_mut() {
+            let mut mat = Matrix::zeros(50, 7);
+            mat.par_rows_mut()
+                .enumerate()
+                .for_each(|(i, row)| row.fill(i as f64));
+            assert_eq!(mat, Matrix::from_fn(50, 7, |i, _| i as f64));
+            assert_eq!(mat.par_rows_mut().len(), 50);
+        }
+
+        #[test]
+        fn test_par_rows_mut_no_columns() {
+            let mut mat = Matrix::zeros(3, 0);
+            let serial = mat.rows_mut().count();
+            assert_eq!(serial, 3);
+            assert_eq!(mat.par_rows_mut().count(), serial);
+            assert!(mat.par_rows_mut().all(|row| row.is_empty()));
+            assert_eq!(Matrix::zeros(0, 0).par_rows_mut().count(), 0);
+        }
+    }
+
+    #[test]
+    fn test_scale_row() {
+        let mut mat = Matrix::from_rows(&[&[1., 2.], &[3., 4.]]).unwrap();
+        mat.scale_row(1, 2.);
+        assert_eq!(mat, Matrix::from_rows(&[&[1., 2.], &[6., 8.]]).unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the shape is (2, 2) but the row index is 2.")]
+    fn test_scale_row_out_of_bounds() {
+        Matrix::zeros(2, 2).scale_row(2, 1.);
+    }
+
+    #[test]
+    fn test_relu() {
+        let mat = Matrix::from_rows(&[&[-1., 0.], &[2.5, -0.1]]).unwrap();
+        assert_eq!(
+            mat.relu(),
+            Matrix::from_rows(&[&[0., 0.], &[2.5, 0.]]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_sigmoid() {
+        let mat = Matrix::from_rows(&[&[0., 2.], &[-2., 50.]]).unwrap();
+        let s = mat.sigmoid();
+        assert_eq!(s.shape(), (2, 2));
+        assert_eq!(s[(0, 0)], 0.5);
+        assert!((s[(0, 1)] + s[(1, 0)] - 1.).abs() < 1e-15);
+        assert!((s[(1, 1)] - 1.).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_jacobi_and_gauss_seidel_converge() {
+        let a = Matrix::from_rows(&[&[4., -1., 0.], &[-1., 4., -1.], &[0., -1., 4.]]).unwrap();
+        let b = Matrix::from_rows(&[&[2., 1.], &[4., 0.], &[10., -1.]]).unwrap();
+        let direct = a.solve(&b).unwrap();
+        let jacobi = a.solve_jacobi(&b, 1e-12, 200).unwrap();
+        let seidel = a.solve_gauss_seidel(&b, 1e-12, 200).unwrap();
+        for result in [&jacobi, &seidel] {
+            assert!(result.converged);
+            assert!(!result.diverging);
+            assert!(result.residual <= 1e-12);
+            assert!(approx_eq(&result.solution, &direct, 1e-10));
+        }
+        assert!(seidel.iterations < jacobi.iterations);
+    }
+
+    #[test]
+    fn test_iterative_zero_diagonal() {
+        let a = Matrix::from_rows(&[&[1., 2.], &[3., 0.]]).unwrap();
+        let b = Matrix::ones(2, 1);
+        let err = a.solve_jacobi(&b, 1e-10, 10).unwrap_err();
+        assert_eq!(err.to_string(), "Diagonal element (1, 1) is zero");
+        assert!(a.solve_gauss_seidel(&b, 1e-10, 10).is_err());
+        assert_eq!(
+            Matrix::identity(3).solve_jacobi(&b, 1e-10, 10),
+            Err(LinalgError::ShapeMismatch {
+                expected: (3, 1),
+                found: (2, 1)
+            })
+        );
+    }
+
+    #[test]
+    fn test_iterative_divergence_and_iteration_limit() {
+        let a = Matrix::from_rows(&[&[1., 3.], &[2., 1.]]).unwrap();
+        let b = Matrix::ones(2, 1);
+        let result = a.solve_jacobi(&b, 1e-10, 1000).unwrap();
+        assert!(result.diverging);
+        assert!(!result.converged);
+        assert!(result.iterations < 1000);
+        let dominant = Matrix::from_rows(&[&[3., 1.], &[1., 3.]]).unwrap();
+        let capped = dominant.solve_jacobi(&b, 1e-14, 2).unwrap();
+        assert_eq!(capped.iterations, 2);
+        assert!(!capped.converged && !capped.diverging);
+    }
+
+    #[test]
+    fn test_convolve2d_valid_average() {
+        let mat = Matrix::from_fn(3, 4, |i, j| (i * 4 + j) as f64);
+        let kernel = Matrix::from_scalar(3, 3, 1. / 9.);
+        let out = mat.convolve2d(&kernel, false).unwrap();
+        // Means of the two 3x3 windows: centred on 5 and 6.
+        assert!(approx_eq(
+            &out,
+            &Matrix::from_rows(&[&[5., 6.]]).unwrap(),
+            1e-12
+        ));
+    }
+
+    #[test]
+    fn test_convolve2d_padded_average() {
+        let mat = Matrix::from_rows(&[&[9., 0., 0.], &[0., 0., 0.], &[0., 0., 18.]]).unwrap();
+        let kernel = Matrix::from_scalar(3, 3, 1. / 9.);
+        let out = mat.convolve2d(&kernel, true).unwrap();
+        let expected = Matrix::from_rows(&[&[1., 1., 0.], &[1., 3., 2.], &[0., 2., 2.]]).unwrap();
+        assert!(approx_eq(&out, &expected, 1e-12));
+    }
+
+    #[test]
+    fn test_convolve2d_is_not_flipped() {
+        let mat = Matrix::from_rows(&[&[1., 2., 3.]]).unwrap();
+        let kernel = Matrix::from_rows(&[&[1., 0.]]).unwrap();
+        assert_eq!(
+            mat.convolve2d(&kernel, false).unwrap(),
+            Matrix::from_rows(&[&[1., 2.]]).unwrap()
+        );
+        assert_eq!(
+            mat.convolve2d(&kernel, true).unwrap(),
+            Matrix::from_rows(&[&[1., 2., 3.]]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_convolve2d_errors() {
+        let mat = Matrix::ones(2, 2);
+        assert!(mat.convolve2d(&Matrix::zeros(0, 1), true).is_err());
+        let err = mat.convolve2d(&Matrix::ones(3, 1), false).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Kernel of shape (3, 1) does not fit in a (2, 2) matrix without padding"
+        );
+        assert_eq!(
+            mat.convolve2d(&Matrix::ones(3, 3), true).unwrap(),
+            Matrix::from_scalar(2, 2, 4.)
+        );
+    }
+
+    fn second_difference() -> Matrix {
+        Matrix::from_vec(3, 3, vec![2., -1., 0., -1., 2., -1., 0., -1., 2.]).unwrap()
+    }
+
+    fn assert_eigenpair(a: &Matrix, lambda: f64, v: &[f64]) {
+        let av = a.mul_vec(v).unwrap();
+        for (x, y) in av.iter().zip(v) {
+            assert!((x - lambda * y).abs() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_dominant_eigenpair() {
+        let a = second_difference();
+        let (lambda, v) = a.dominant_eigenpair(1e-14, 1000).unwrap();
+        assert!((lambda - (2. + 2f64.sqrt())).abs() < 1e-10);
+        assert_eigenpair(&a, lambda, &v);
+    }
+
+    #[test]
+    fn test_smallest_eigenpair() {
+        let a = second_difference();
+        let (lambda, v) = a.smallest_eigenpair(1e-14, 1000).unwrap();
+        assert!((lambda - (2. - 2f64.sqrt())).abs() < 1e-10);
+        assert_eigenpair(&a, lambda, &v);
+    }
+
+    #[test]
+    fn test_eigenpair_near() {
+        let a = second_difference();
+        let (lambda, v) = a.eigenpair_near(1.9, 1e-14, 1000).unwrap();
+        assert!((lambda - 2.).abs() < 1e-10);
+        assert_eigenpair(&a, lambda, &v);
+        assert_eq!(
+            a.eigenpair_near(2., 1e-14, 1000),
+            Err(LinalgError::Singular)
+        );
+    }
+
+    #[test]
+    fn test_eigenpair_not_converged() {
+        let a = second_difference();
+        match a.dominant_eigenpair(1e-14, 2) {
+            Err(LinalgError::EigenNotConverged {
+                iterations,
+                estimate,
+            }) => {
+                assert_eq!(iterations, 2);
+                assert!(estimate.is_finite());
+            }
+            other => panic!("expected EigenNotConverged, got {:?}", other),
+        }
+        let b = Matrix::zeros(2, 3);
+        assert_eq!(b.smallest_eigenpair(1e-10, 10), Err(LinalgError::NotSquare));
+    }
+
+    #[test]
+    fn test_svd() {
+        let a =
+            Matrix::from_vec(4, 3, vec![1., 2., 0., 0., 1., 3., 2., 0., 1., 1., 1., 1.]).unwrap();
+        let svd = a.svd().unwrap();
+        assert_eq!(svd.u.shape(), (4, 3));
+        assert_eq!(svd.vt.shape(), (3, 3));
+        assert!(svd.s.windows(2).all(|w| w[0] >= w[1]));
+        assert!(approx_eq(&svd.reconstruct(), &a, 1e-12));
+        let utu = &svd.u.clone().transpose() * &svd.u;
+        assert!(approx_eq(&utu, &Matrix::identity(3), 1e-12));
+        let vvt = &svd.vt * &svd.vt.clone().transpose();
+        assert!(approx_eq(&vvt, &Matrix::identity(3), 1e-12));
+
+        let (values, _) = (&a.clone().transpose() * &a)
+            .symmetric_eig(100, 1e-14)
+            .unwrap();
+        for (s, l) in svd.s.iter().zip(values.iter().rev()) {
+            assert!((s * s - l).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_svd_wide_and_rank_deficient() {
+        let a = Matrix::from_vec(2, 3, vec![1., 2., 3., 2., 4., 6.]).unwrap();
+        let svd = a.svd().unwrap();
+        assert_eq!(svd.u.shape(), (2, 2));
+        assert_eq!(svd.vt.shape(), (2, 3));
+        assert!((svd.s[0] - 70f64.sqrt()).abs() < 1e-12);
+        assert!(svd.s[1].abs() < 1e-12);
+        assert!(approx_eq(&svd.reconstruct(), &a, 1e-12));
+    }
+
+    #[test]
+    fn test_trace_of_product() {
+        let a = Matrix::from_vec(2, 3, vec![1., 2., 3., 4., 5., 6.]).unwrap();
+        let b = Matrix::from_vec(3, 2, vec![7., -8., 9., 10., -11., 12.]).unwrap();
+        assert_eq!(a.trace_of_product(&b).unwrap(), (&a * &b).trace().unwrap());
+        assert_eq!(b.trace_of_product(&a).unwrap(), (&b * &a).trace().unwrap());
+        assert_eq!(
+            a.trace_of_product(&a),
+            Err(LinalgError::ShapeMismatch {
+                expected: (3, 2),
+                found: (2, 3)
+            })
+        );
+    }
+
+    #[test]
+    fn test_frobenius_inner() {
+        let a = Matrix::from_vec(2, 3, vec![1., -2., 3., 4., 0.5, -6.]).unwrap();
+        let b = Matrix::from_vec(2, 3, vec![2., 1., 0., -1., 4., 1.]).unwrap();
+        assert!((a.frobenius_inner(&a).unwrap() - a.frobenius_norm().powi(2)).abs() < 1e-12);
+        assert_eq!(a.frobenius_inner(&b).unwrap(), -8.);
+        assert_eq!(
+            a.frobenius_inner(&Matrix::zeros(3, 2)),
+            Err(LinalgError::ShapeMismatch {
+                expected: (2, 3),
+                found: (3, 2)
+            })
+        );
+    }
+
+    #[test]
+    fn test_lu_factor_solve() {
+        let a = Matrix::from_vec(3, 3, vec![2., 1., 1., 4., -6., 0., -2., 7., 2.]).unwrap();
+        let b = Matrix::from_vec(3, 2, vec![5., 1., -2., 0., 9., 2.]).unwrap();
+        let lu = a.lu_factor().unwrap();
+        assert!(approx_eq(
+            &lu.solve(&b).unwrap(),
+            &a.solve(&b).unwrap(),
+            1e-12
+        ));
+        assert!(matches!(
+            lu.solve(&Matrix::zeros(2, 1)),
+            Err(LinalgError::ShapeMismatch { .. })
+        ));
+        assert_eq!(Matrix::zeros(2, 3).lu_factor(), Err(LinalgError::NotSquare));
+        assert_eq!(Matrix::zeros(2, 2).lu_factor(), Err(LinalgError::Singular));
+    }
+
+    #[test]
+    fn test_solve_refined_hilbert() {
+        let n = 10;
+        let a = Matrix::from_fn(n, n, |i, j| 1. / (i + j + 1) as f64);
+        let b = &a * &Matrix::from_vec(n, 1, vec![1.; n]).unwrap();
+        let lu = a.lu_factor().unwrap();
+        let unrefined = lu.solve_refined(&a, &b, 0).unwrap();
+        assert_eq!(unrefined.iterations, 0);
+        assert_eq!(unrefined.solution, lu.solve(&b).unwrap());
+        let refined = lu.solve_refined(&a, &b, 10).unwrap();
+        assert!(refined.iterations >= 1);
+        assert!(refined.residual < unrefined.residual);
+    }
+
+    #[test]
+    fn test_solve_refined_stops_early() {
+        let a = Matrix::from_vec(2, 2, vec![2., 0., 0., 4.]).unwrap();
+        let b = Matrix::from_vec(2, 1, vec![1., 2.]).unwrap();
+        let refined = a.lu_factor().unwrap().solve_refined(&a, &b, 10).unwrap();
+        assert_eq!(refined.iterations, 0);
+        assert_eq!(refined.residual, 0.);
+        assert_eq!(refined.solution.into_vec(), vec![0.5, 0.5]);
+        assert!(matches!(
+            a.lu_factor()
+                .unwrap()
+                .solve_refined(&Matrix::identity(3), &b, 10),
+            Err(LinalgError::ShapeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_is_positive_definite() {
+        let spd = Matrix::from_vec(3, 3, vec![4., 1., 2., 1., 3., 0., 2., 0., 5.]).unwrap();
+        assert!(spd.is_positive_definite());
+        let indefinite = Matrix::from_vec(2, 2, vec![1., 2., 2., 1.]).unwrap();
+        assert!(!indefinite.is_positive_definite());
+        let semidefinite = Matrix::from_vec(2, 2, vec![1., 1., 1., 1.]).unwrap();
+        assert!(!semidefinite.is_positive_definite());
+        let asymmetric = Matrix::from_vec(2, 2, vec![2., 1., 0., 2.]).unwrap();
+        assert!(!asymmetric.is_positive_definite());
+        let tiny = Matrix::from_vec(2, 2, vec![2., 5., 0., 2.]).unwrap() * 1e-13;
+        assert!(!tiny.is_positive_definite());
+        assert!(!Matrix::zeros(2, 3).is_positive_definite());
+    }
+
+    #[test]
+    fn test_solve_tridiagonal() {
+        let n = 6;
+        let a = Matrix::from_fn(n, n, |i, j| match i.abs_diff(j) {
+            0 => 2.,
+            1 => -1.,
+            _ => 0.,
+        });
+        assert!(a.is_tridiagonal(0.));
+        let b: Vec<f64> = (0..n).map(|i| (i as f64).sin() + 1.).collect();
+        let x = Matrix::solve_tridiagonal(&[-1.; 5], &[2.; 6], &[-1.; 5], &b).unwrap();
+        let dense = a.solve(&Matrix::from_vec(n, 1, b).unwrap()).unwrap();
+        for (x, y) in x.iter().zip(dense.into_vec()) {
+            assert!((x - y).abs() < 1e-12);
+        }
+
+        let mut full = a.clone();
+        full[(0, 2)] = 1e-3;
+        assert!(!full.is_tridiagonal(1e-6));
+        assert!(full.is_tridiagonal(1e-2));
+        assert!(!Matrix::zeros(2, 3).is_tridiagonal(0.));
+    }
+
+    #[test]
+    fn test_solve_tridiagonal_tiny_scale() {
+        // 1e-13 * [[2, -1, 0], [-1, 2, -1], [0, -1, 2]] * [1, 2, 3] = 1e-13 * [0, 0, 4].
+        let x =
+            Matrix::solve_tridiagonal(&[-1e-13; 2], &[2e-13; 3], &[-1e-13; 2], &[0., 0., 4e-13])
+                .unwrap();
+        for (x, y) in x.iter().zip([1., 2., 3.]) {
+            assert!((x - y).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_solve_tridiagonal_small_and_invalid() {
+        assert_eq!(
+            Matrix::solve_tridiagonal(&[], &[4.], &[], &[2.]).unwrap(),
+            vec![0.5]
+        );
+        let x = Matrix::solve_tridiagonal(&[1.], &[2., 3.], &[1.], &[3., 4.]).unwrap();
+        assert!((x[0] - 1.).abs() < 1e-12 && (x[1] - 1.).abs() < 1e-12);
+
+        assert_eq!(
+            Matrix::solve_tridiagonal(&[1., 1.], &[2., 3.], &[1.], &[3., 4.]),
+            Err(LinalgError::LengthMismatch {
+                expected: 1,
+                found: 2
+            })
+        );
+        assert_eq!(
+            Matrix::solve_tridiagonal(&[1.], &[2., 3.], &[1.], &[3.]),
+            Err(LinalgError::LengthMismatch {
+                expected: 2,
+                found: 1
+            })
+        );
+        assert!(matches!(
+            Matrix::solve_tridiagonal(&[1.], &[0., 1.], &[1.], &[1., 1.]),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_banded_round_trip() {
+        let a = Matrix::from_fn(5, 5, |i, j| {
+            if i <= j + 2 && j <= i + 1 {
+                (i * 5 + j + 1) as f64
+            } else {
+                0.
+            }
+        });
+        let banded = a.to_banded(2, 1, 0.).unwrap();
+        assert_eq!(banded.size(), 5);
+        assert_eq!(banded.bandwidths(), (2, 1));
+        assert_eq!(banded.get(3, 1), 17.);
+        assert_eq!(banded.get(0, 4), 0.);
+        assert_eq!(banded.to_dense(), a);
+        assert_eq!(a.to_banded(3, 3, 0.).unwrap().to_dense(), a);
+
+        let ab = Matrix::from_vec(3, 3, vec![0., 1., 2., 3., 4., 5., 6., 7., 0.]).unwrap();
+        let from_bands = BandedMatrix::from_bands(1, 1, ab).unwrap();
+        let expected = Matrix::from_vec(3, 3, vec![3., 1., 0., 6., 4., 2., 0., 7., 5.]).unwrap();
+        assert_eq!(from_bands.to_dense(), expected);
+        assert!(matches!(
+            BandedMatrix::from_bands(1, 0, Matrix::zeros(3, 3)),
+            Err(LinalgError::ShapeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_to_banded_rejects_out_of_band() {
+        let a = Matrix::from_fn(4, 4, |i, j| (i + j + 1) as f64);
+        assert!(matches!(
+            a.to_banded(1, 1, 0.),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+        assert!(a.to_banded(3, 3, 0.).is_ok());
+        assert_eq!(
+            Matrix::zeros(2, 3).to_banded(1, 1, 0.),
+            Err(LinalgError::NotSquare)
+        );
+    }
+
+    #[test]
+    fn test_to_banded_tolerance() {
+        let mut a = Matrix::identity(4) * 2.;
+        a[(0, 3)] = 1e-300;
+        a[(3, 1)] = -1e-14;
+        assert!(matches!(
+            a.to_banded(0, 0, 0.),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+        let banded = a.to_banded(0, 0, 1e-12).unwrap();
+        assert_eq!(banded.to_dense(), Matrix::identity(4) * 2.);
+        assert!(matches!(
+            a.to_banded(0, 0, 1e-15),
+            Err(LinalgError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_banded_lu_factor_reuse() {
+        let n = 8;
+        let a = Matrix::from_fn(n, n, |i, j| match i.abs_diff(j) {
+            0 => 4. + i as f64,
+            1 => -1.,
+            2 if i > j => 0.5,
+            _ => 0.,
+        });
+        let lu = a.to_banded(2, 1, 0.).unwrap().lu_factor().unwrap();
+        for k in 0..3 {
+            let b = Matrix::from_fn(n, 1, |i, _| (i * k) as f64 - 1.);
+            assert!(approx_eq(
+                &lu.solve(&b).unwrap(),
+                &a.solve(&b).unwrap(),
+                1e-12
+            ));
+        }
+        assert!(matches!(
+            lu.solve(&Matrix::zeros(3, 1)),
+            Err(LinalgError::ShapeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_banded_solve() {
+        let n = 12;
+        let (kl, ku) = (2, 3);
+        let a = Matrix::from_fn(n, n, |i, j| {
+            if i > j + kl || j > i + ku {
+                0.
+            } else {
+                ((3 * i + 7 * j) as f64).sin()
+            }
+        });
+        let b = Matrix::from_fn(n, 2, |i, j| (i + 3 * j) as f64 - 4.);
+        let banded = a.to_banded(kl, ku, 0.).unwrap();
+        assert!(approx_eq(
+            &banded.solve(&b).unwrap(),
+            &a.solve(&b).unwrap(),
+            1e-9
+        ));
+        assert!(matches!(
+            banded.solve(&Matrix::zeros(3, 1)),
+            Err(LinalgError::ShapeMismatch { .. })
+        ));
+        let tiny = a.clone() * 1e-13;
+        assert!(approx_eq(
+            &tiny.to_banded(kl, ku, 0.).unwrap().solve(&b).unwrap(),
+            &tiny.solve(&b).unwrap(),
+            1e-9 * 1e13
+        ));
+        let singular = Matrix::zeros(3, 3).to_banded(1, 1, 0.).unwrap();
+        assert_eq!(
+            singular.solve(&Matrix::zeros(3, 1)),
+            Err(LinalgError::Singular)
+        );
+    }
+
+    #[test]
+    fn test_logdet() {
+        let a = Matrix::from_vec(3, 3, vec![4., 1., 2., -1., 3., 0., 2., 1., -5.]).unwrap();
+        let det = a.determinant().unwrap();
+        let (sign, logabsdet) = a.logdet().unwrap();
+        assert_eq!(sign, det.signum());
+        assert!((logabsdet - det.abs().ln()).abs() < 1e-12);
+
+        let large = Matrix::identity(320) * 10.;
+        assert_eq!(large.determinant().unwrap(), f64::INFINITY);
+        let (sign, logabsdet) = large.logdet().unwrap();
+        assert_eq!(sign, 1.);
+        assert!((logabsdet - 320. * 10f64.ln()).abs() < 1e-9);
+
+        assert_eq!(
+            Matrix::zeros(2, 2).logdet().unwrap(),
+            (0., f64::NEG_INFINITY)
+        );
+        assert_eq!(Matrix::zeros(2, 3).logdet(), Err(LinalgError::NotSquare));
+    }
+
+    #[test]
+    fn test_logdet_tiny_scale() {
+        let tiny = Matrix::identity(3) * 1e-13;
+        let (sign, logabsdet) = tiny.logdet().unwrap();
+        assert_eq!(sign, 1.);
+        assert!((logabsdet - 3. * 1e-13f64.ln()).abs() < 1e-9);
+        assert!((tiny.determinant().unwrap() - 1e-39).abs() < 1e-50);
+        assert!(tiny.solve(&Matrix::ones(3, 1)).is_ok());
+    }
+
+    #[test]
+    fn test_dominant_eigenpair_symmetric_spectrum() {
+        let a = Matrix::from_vec(2, 2, vec![2., -1., -1., 2.]).unwrap();
+        let (lambda, v) = a.dominant_eigenpair(1e-12, 1000).unwrap();
+        assert!((lambda - 3.).abs() < 1e-10);
+        assert_eigenpair(&a, lambda, &v);
+
+        let opposite = Matrix::from_vec(2, 2, vec![2., 0., 0., -2.]).unwrap();
+        assert!(matches!(
+            opposite.dominant_eigenpair(1e-12, 1000),
+            Err(LinalgError::EigenNotConverged {
+                iterations: 1000,
+                ..
+            })
+        ));
+    }
 }